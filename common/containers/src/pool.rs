@@ -18,7 +18,9 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 
 use async_trait::async_trait;
 use common_base::tokio;
@@ -62,6 +64,9 @@ where Mgr: ItemManager
     /// Pooled items indexed by key.
     pub items: Arc<Mutex<HashMap<Mgr::Key, PoolItem<Mgr::Item>>>>,
 
+    /// When an item is returned by `get()` the last time, indexed by key.
+    last_used: Arc<Mutex<HashMap<Mgr::Key, Instant>>>,
+
     manager: Mgr,
 
     err_type: PhantomData<Mgr::Error>,
@@ -78,11 +83,81 @@ where
         Pool {
             initial_retry_interval,
             items: Default::default(),
+            last_used: Default::default(),
             manager,
             err_type: Default::default(),
         }
     }
 
+    /// Start a background task to drop the items not returned by `get()` for longer than `idle_timeout`.
+    ///
+    /// A dropped item is built again on the next `get()`.
+    /// The task stops when the pool is dropped.
+    pub fn with_idle_timeout(self, idle_timeout: Duration) -> Self
+    where
+        Mgr: 'static,
+        Mgr::Key: Sync + 'static,
+        Mgr::Item: 'static,
+    {
+        let items = Arc::downgrade(&self.items);
+        let last_used = Arc::downgrade(&self.last_used);
+
+        tokio::spawn(async move {
+            loop {
+                sleep(idle_timeout / 2).await;
+                if !Self::reap_idle(&items, &last_used, idle_timeout) {
+                    break;
+                }
+            }
+        });
+        self
+    }
+
+    /// Drop the idle items, returns false if the pool is already dropped.
+    ///
+    /// An item being built or checked by `get()` is locked and is not reaped.
+    /// An item already returned is a clone, dropping the pooled one does not affect the one in use.
+    #[allow(clippy::type_complexity)]
+    fn reap_idle(
+        items: &Weak<Mutex<HashMap<Mgr::Key, PoolItem<Mgr::Item>>>>,
+        last_used: &Weak<Mutex<HashMap<Mgr::Key, Instant>>>,
+        idle_timeout: Duration,
+    ) -> bool {
+        let (items, last_used) = match (items.upgrade(), last_used.upgrade()) {
+            (Some(items), Some(last_used)) => (items, last_used),
+            _ => return false,
+        };
+
+        let items = items.lock().unwrap();
+        let mut last_used = last_used.lock().unwrap();
+
+        last_used.retain(|key, used_at| {
+            if used_at.elapsed() < idle_timeout {
+                return true;
+            }
+
+            let pool_item = match items.get(key) {
+                None => return false,
+                Some(pool_item) => pool_item,
+            };
+
+            match pool_item.try_lock() {
+                Ok(mut guard) => {
+                    tracing::info!("drop idle item of key: {:?}", key);
+                    *guard = None;
+                    false
+                }
+                Err(_) => true,
+            }
+        });
+        true
+    }
+
+    fn touch(&self, key: &Mgr::Key) {
+        let mut last_used = self.last_used.lock().unwrap();
+        last_used.insert(key.clone(), Instant::now());
+    }
+
     /// Return an raw pool item.
     ///
     /// The returned one may be an uninitialized one, i.e., it contains a None.
@@ -114,6 +189,7 @@ where
             tracing::debug!("check reused item res: {:?}", check_res);
 
             if let Ok(itm) = check_res {
+                self.touch(key);
                 return Ok(itm);
             } else {
                 // mark broken conn as deleted
@@ -135,6 +211,7 @@ where
             match new_item {
                 Ok(x) => {
                     *guard = Some(x.clone());
+                    self.touch(key);
                     return Ok(x);
                 }
                 Err(err) => {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -84,3 +86,50 @@ impl ItemManager for FooMgr {
         }
     }
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pool_idle_timeout() -> anyhow::Result<()> {
+    let p = Pool::new(CountingMgr::default(), Duration::from_millis(10))
+        .with_idle_timeout(Duration::from_millis(100));
+
+    let i1 = p.get(&1).await?;
+    assert_eq!(1, i1.seq, "make a new item(1)");
+
+    let i1_reused = p.get(&1).await?;
+    assert_eq!(1, i1_reused.seq, "item(1) is reused before idle timeout");
+
+    tracing::info!("--- idle item is dropped after idle timeout");
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    {
+        let items = p.items.lock().unwrap();
+        let item = items.get(&1).unwrap().try_lock().unwrap();
+        assert!(item.is_none(), "item(1) is reaped");
+    }
+
+    tracing::info!("--- reaped item is built again on demand");
+    let i1_rebuilt = p.get(&1).await?;
+    assert_eq!(2, i1_rebuilt.seq, "make a new item(1)");
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct CountingMgr {
+    seq: AtomicUsize,
+}
+
+#[async_trait]
+impl ItemManager for CountingMgr {
+    type Key = u32;
+    type Item = Item;
+    type Error = anyhow::Error;
+
+    async fn build(&self, key: &Self::Key) -> Result<Self::Item, Self::Error> {
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(Item { key: *key, seq })
+    }
+
+    async fn check(&self, item: Self::Item) -> Result<Self::Item, Self::Error> {
+        Ok(item)
+    }
+}
@@ -188,6 +188,32 @@ impl DataBlock {
         })
     }
 
+    /// Mark the nullable fields whose columns have no null rows as not nullable,
+    /// so the following operators can skip the null checking.
+    /// Null typed fields are kept as they are, their columns carry no validity bitmap.
+    #[must_use]
+    pub fn collapse_nullables(&self) -> Self {
+        let fields = self
+            .schema
+            .fields()
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(field, column)| {
+                if field.is_nullable()
+                    && field.data_type() != &DataType::Null
+                    && column.get_validity().all_valid()
+                {
+                    DataField::new(field.name(), field.data_type().clone(), false)
+                        .with_default_expr(field.default_expr().clone())
+                } else {
+                    field.clone()
+                }
+            })
+            .collect();
+
+        DataBlock::create(Arc::new(DataSchema::new(fields)), self.columns.clone())
+    }
+
     #[inline]
     pub fn resort(self, schema: DataSchemaRef) -> Result<Self> {
         let mut columns = Vec::with_capacity(self.num_columns());
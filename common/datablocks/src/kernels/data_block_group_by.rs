@@ -32,7 +32,9 @@ impl DataBlock {
         for col in column_names {
             let column = block.try_column_by_name(col)?;
             let typ = column.data_type();
-            if typ.is_integer() {
+            // Fixed keys have no room for the null marker.
+            let nullable = block.schema().field_with_name(col)?.is_nullable();
+            if typ.is_integer() && !nullable {
                 group_key_len += typ.numeric_byte_size()?;
             } else {
                 return Ok(HashMethodKind::Serializer(HashMethodSerializer::default()));
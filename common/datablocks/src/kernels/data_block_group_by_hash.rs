@@ -20,6 +20,7 @@ use std::marker::PhantomData;
 use common_datavalues::prelude::*;
 use common_datavalues::DataValue;
 use common_exception::Result;
+use common_io::prelude::*;
 
 use crate::DataBlock;
 
@@ -173,8 +174,12 @@ impl HashMethodSerializer {
             let data_type = f.data_type();
             let mut deserializer = data_type.create_deserializer(rows)?;
 
-            for (_row, key) in keys.iter_mut().enumerate() {
-                deserializer.de(key)?;
+            for key in keys.iter_mut() {
+                let marker: u8 = key.read_scalar()?;
+                match marker {
+                    GROUP_KEY_NULL => deserializer.de_null(),
+                    _ => deserializer.de(key)?,
+                }
             }
             res.push(deserializer.finish_to_series());
         }
@@ -194,10 +199,11 @@ impl HashMethod for HashMethodSerializer {
             let mut group_key_len = 0;
             for col in group_columns {
                 let typ = col.data_type();
+                // One more byte for the null marker.
                 if typ.is_integer() {
-                    group_key_len += typ.numeric_byte_size()?;
+                    group_key_len += typ.numeric_byte_size()? + 1;
                 } else {
-                    group_key_len += 4;
+                    group_key_len += 5;
                 }
             }
 
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
@@ -38,3 +40,30 @@ fn test_data_block() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_collapse_nullables() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::Int64, true),
+        DataField::new("c", DataType::Int64, false),
+        DataField::new("d", DataType::Null, true),
+    ]);
+
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![Some(1i64), Some(2), Some(3)]),
+        Series::new(vec![Some(1i64), None, Some(3)]),
+        Series::new(vec![1i64, 2, 3]),
+        DFNullArray::new(NullArray::new_null(ArrowType::Null, 3)).into_series(),
+    ]);
+
+    let collapsed = block.collapse_nullables();
+    assert!(!collapsed.schema().field_with_name("a")?.is_nullable());
+    assert!(collapsed.schema().field_with_name("b")?.is_nullable());
+    assert!(!collapsed.schema().field_with_name("c")?.is_nullable());
+    assert!(collapsed.schema().field_with_name("d")?.is_nullable());
+    assert_eq!(collapsed.num_rows(), 3);
+    assert_eq!(collapsed.column(0).try_get(1)?, DataValue::Int64(Some(2)));
+
+    Ok(())
+}
@@ -59,3 +59,33 @@ fn test_data_block_concat() -> Result<()> {
     common_datablocks::assert_blocks_eq(expected, &[results]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_concat_null_column() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Null, true),
+    ]);
+
+    let blocks = (0..100)
+        .map(|i| {
+            DataBlock::create(schema.clone(), vec![
+                Series::new(vec![i as i64, i as i64]).into(),
+                DataColumn::Constant(DataValue::Null, 2),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    assert_eq!(results.num_rows(), 200);
+
+    // The null columns are merged by summing the lengths, still a constant column without arrow concat.
+    match results.column(1) {
+        DataColumn::Constant(value, length) => {
+            assert!(value.is_null());
+            assert_eq!(*length, 200);
+        }
+        DataColumn::Array(_) => panic!("null columns should not be concatenated as arrays"),
+    }
+    Ok(())
+}
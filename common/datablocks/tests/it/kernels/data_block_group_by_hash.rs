@@ -59,3 +59,74 @@ fn test_data_block_group_by_hash() -> Result<()> {
     ]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_group_by_hash_serializer_with_null() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("n", DataType::Null, true),
+        DataField::new("x", DataType::String, false),
+    ]);
+
+    let block = DataBlock::create(schema.clone(), vec![
+        DataColumn::Constant(DataValue::Null, 3),
+        Series::new(vec!["x1", "x2", "x1"]).into(),
+    ]);
+
+    let method = DataBlock::choose_hash_method(&block, &["n".to_string(), "x".to_string()])?;
+    assert_eq!(method.name(), HashMethodSerializer::default().name());
+
+    let hash = HashMethodSerializer::default();
+    let group_columns = vec![
+        block.try_column_by_name("n")?,
+        block.try_column_by_name("x")?,
+    ];
+    let keys = hash.build_keys(&group_columns, block.num_rows())?;
+    assert_eq!(keys[0], keys[2]);
+    assert_ne!(keys[0], keys[1]);
+
+    let columns = hash.de_group_columns(keys, schema.fields())?;
+    assert_eq!(columns[0].data_type(), &DataType::Null);
+    assert_eq!(columns[0].len(), 3);
+    assert_eq!(columns[0].null_count(), 3);
+    assert_eq!(
+        columns[1].try_get(1)?,
+        DataValue::String(Some(b"x2".to_vec()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_data_block_group_by_hash_with_nullable_key() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, true)]);
+
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![
+        Some(1i32),
+        None,
+        Some(1),
+        None,
+        Some(0),
+    ])]);
+
+    let method = DataBlock::choose_hash_method(&block, &["a".to_string()])?;
+    assert_eq!(method.name(), HashMethodSerializer::default().name());
+
+    let hash = HashMethodSerializer::default();
+    let group_indices = hash.group_by_get_indices(&block, &["a".to_string()])?;
+    let mut groups = group_indices
+        .values()
+        .map(|(indices, _)| indices.clone())
+        .collect::<Vec<_>>();
+    groups.sort();
+    assert_eq!(groups, vec![vec![0, 2], vec![1, 3], vec![4]]);
+
+    let group_columns = vec![block.try_column_by_name("a")?];
+    let keys = hash.build_keys(&group_columns, block.num_rows())?;
+    let columns = hash.de_group_columns(keys, schema.fields())?;
+    assert_eq!(columns[0].len(), 5);
+    assert_eq!(columns[0].null_count(), 2);
+    assert_eq!(columns[0].try_get(1)?, DataValue::Int32(None));
+    assert_eq!(columns[0].try_get(4)?, DataValue::Int32(Some(0)));
+
+    Ok(())
+}
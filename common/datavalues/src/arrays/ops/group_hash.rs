@@ -32,6 +32,9 @@ pub trait GroupHash: Debug {
         )))
     }
 
+    /// Append the value of each row to its key, prefixed with a marker byte:
+    /// `GROUP_KEY_VALUE` for a value, `GROUP_KEY_NULL` for null.
+    /// So the null rows are grouped together, apart from any value.
     fn serialize(&self, _vec: &mut Vec<Vec<u8>>) -> Result<()> {
         Err(ErrorCode::BadDataValueType(format!(
             "Unsupported apply fn serialize operation for {:?}",
@@ -40,6 +43,9 @@ pub trait GroupHash: Debug {
     }
 }
 
+pub const GROUP_KEY_NULL: u8 = 0;
+pub const GROUP_KEY_VALUE: u8 = 1;
+
 impl<T> GroupHash for DFPrimitiveArray<T>
 where
     T: DFPrimitiveType,
@@ -68,8 +74,14 @@ where
 
     fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
         assert_eq!(vec.len(), self.len());
-        for (value, vec) in self.into_no_null_iter().zip(vec.iter_mut()) {
-            BinaryWrite::write_scalar(vec, value)?;
+        for (value, vec) in self.iter().zip(vec.iter_mut()) {
+            match value {
+                Some(value) => {
+                    BinaryWrite::write_scalar(vec, &GROUP_KEY_VALUE)?;
+                    BinaryWrite::write_scalar(vec, value)?;
+                }
+                None => BinaryWrite::write_scalar(vec, &GROUP_KEY_NULL)?,
+            }
         }
         Ok(())
     }
@@ -91,8 +103,14 @@ impl GroupHash for DFBooleanArray {
 
     fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
         assert_eq!(vec.len(), self.len());
-        for (value, vec) in self.into_no_null_iter().zip(vec.iter_mut()) {
-            BinaryWrite::write_scalar(vec, &value)?;
+        for (value, vec) in self.into_iter().zip(vec.iter_mut()) {
+            match value {
+                Some(value) => {
+                    BinaryWrite::write_scalar(vec, &GROUP_KEY_VALUE)?;
+                    BinaryWrite::write_scalar(vec, &value)?;
+                }
+                None => BinaryWrite::write_scalar(vec, &GROUP_KEY_NULL)?,
+            }
         }
         Ok(())
     }
@@ -101,13 +119,27 @@ impl GroupHash for DFBooleanArray {
 impl GroupHash for DFStringArray {
     fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
         assert_eq!(vec.len(), self.len());
-        for (value, vec) in self.into_no_null_iter().zip(vec.iter_mut()) {
-            BinaryWrite::write_binary(vec, value)?;
+        for (value, vec) in self.into_iter().zip(vec.iter_mut()) {
+            match value {
+                Some(value) => {
+                    BinaryWrite::write_scalar(vec, &GROUP_KEY_VALUE)?;
+                    BinaryWrite::write_binary(vec, value)?;
+                }
+                None => BinaryWrite::write_scalar(vec, &GROUP_KEY_NULL)?,
+            }
         }
         Ok(())
     }
 }
 
 impl GroupHash for DFListArray {}
-impl GroupHash for DFNullArray {}
+impl GroupHash for DFNullArray {
+    fn serialize(&self, vec: &mut Vec<Vec<u8>>) -> Result<()> {
+        assert_eq!(vec.len(), self.len());
+        for vec in vec.iter_mut() {
+            BinaryWrite::write_scalar(vec, &GROUP_KEY_NULL)?;
+        }
+        Ok(())
+    }
+}
 impl GroupHash for DFStructArray {}
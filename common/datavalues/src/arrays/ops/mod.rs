@@ -37,6 +37,8 @@ pub use cast::*;
 pub use contain::*;
 pub use fill::*;
 pub use group_hash::GroupHash;
+pub use group_hash::GROUP_KEY_NULL;
+pub use group_hash::GROUP_KEY_VALUE;
 pub use like::*;
 pub use r#if::*;
 pub use scatter::*;
@@ -24,6 +24,20 @@ pub struct DataColumnCommon;
 
 impl DataColumnCommon {
     pub fn concat(columns: &[DataColumn]) -> Result<DataColumn> {
+        // All the columns are the same constant null, just sum the lengths, no need to concat the arrays.
+        if let Some(DataColumn::Constant(first, _)) = columns.first() {
+            let all_same_null = first.is_null()
+                && columns.iter().all(|column| match column {
+                    DataColumn::Constant(value, _) => value == first,
+                    DataColumn::Array(_) => false,
+                });
+
+            if all_same_null {
+                let length = columns.iter().map(|column| column.len()).sum();
+                return Ok(DataColumn::Constant(first.clone(), length));
+            }
+        }
+
         let arrays = columns
             .iter()
             .map(|s| s.get_array_ref())
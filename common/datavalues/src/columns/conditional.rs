@@ -18,14 +18,24 @@ use crate::prelude::*;
 
 impl DataColumn {
     pub fn if_then_else(&self, lhs: &DataColumn, rhs: &DataColumn) -> Result<DataColumn> {
-        let cond = self.to_minimal_array()?;
+        // Constants are only kept minimal if all the arguments are constant,
+        // otherwise the kernel requires arrays with the same length.
+        let all_constant = [self, lhs, rhs]
+            .iter()
+            .all(|column| matches!(column, DataColumn::Constant(_, _)));
+        let to_array = |column: &DataColumn| match all_constant {
+            true => column.to_minimal_array(),
+            false => column.to_array(),
+        };
+
+        let cond = to_array(self)?;
 
         let dtype = aggregate_types(&[lhs.data_type(), rhs.data_type()])?;
-        let mut left = lhs.to_minimal_array()?;
+        let mut left = to_array(lhs)?;
         if left.data_type() != &dtype {
             left = left.cast_with_type(&dtype)?;
         }
-        let mut right = rhs.to_minimal_array()?;
+        let mut right = to_array(rhs)?;
         if right.data_type() != &dtype {
             right = right.cast_with_type(&dtype)?;
         }
@@ -272,6 +272,9 @@ impl DataColumn {
         }
     }
 
+    /// Mask the column with the validities, e.g. of the arguments of the function producing it.
+    /// A row is null in the result if it is null in the column itself or in any of the validities,
+    /// i.e. the column's own validity is ANDed with all of the given ones.
     #[inline]
     pub fn apply_validities(
         self,
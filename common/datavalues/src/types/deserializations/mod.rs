@@ -21,12 +21,14 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod null;
 mod number;
 mod string;
 
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use null::*;
 pub use number::*;
 pub use string::*;
 
@@ -72,6 +74,7 @@ impl DataType {
                 DataType::Interval(_) => Ok(Box::new(DateDeserializer::<i64> {
                     builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
                 })),
+                DataType::Null => Ok(Box::new(NullDeserializer::default())),
                 other => Err(ErrorCode::BadDataValueType(format!(
                     "create_deserializer does not support type '{:?}'",
                     other
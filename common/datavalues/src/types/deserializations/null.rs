@@ -0,0 +1,50 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+#[derive(Debug, Default)]
+pub struct NullDeserializer {
+    pub length: usize,
+}
+
+impl TypeDeserializer for NullDeserializer {
+    fn de(&mut self, _reader: &mut &[u8]) -> Result<()> {
+        self.length += 1;
+        Ok(())
+    }
+
+    fn de_batch(&mut self, _reader: &[u8], _step: usize, rows: usize) -> Result<()> {
+        self.length += rows;
+        Ok(())
+    }
+
+    fn de_text(&mut self, _reader: &[u8]) -> Result<()> {
+        self.length += 1;
+        Ok(())
+    }
+
+    fn de_null(&mut self) {
+        self.length += 1;
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        let length = std::mem::take(&mut self.length);
+        DFNullArray::new(NullArray::new_null(ArrowType::Null, length)).into_series()
+    }
+}
@@ -25,6 +25,7 @@ mod string;
 mod r#struct;
 
 pub use boolean::*;
+pub use common_io::prelude::DEFAULT_NULL_DISPLAY;
 pub use date::*;
 pub use date_time::*;
 pub use nulls::*;
@@ -39,8 +40,15 @@ pub trait TypeSerializer: Send + Sync {
 
 impl DataType {
     pub fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        match self {
-            DataType::Null => Box::new(NullSerializer {}),
+        self.create_text_serializer(DEFAULT_NULL_DISPLAY)
+    }
+
+    /// Create a serializer rendering nulls as `null_display`, including the nulls nested in structs.
+    pub fn create_text_serializer(&self, null_display: &str) -> Box<dyn TypeSerializer> {
+        let inner: Box<dyn TypeSerializer> = match self {
+            DataType::Null => Box::new(NullSerializer {
+                null_display: null_display.to_owned(),
+            }),
             DataType::Boolean => Box::new(BooleanSerializer {}),
             DataType::UInt8 => Box::new(NumberSerializer::<u8>::default()),
             DataType::UInt16 => Box::new(NumberSerializer::<u16>::default()),
@@ -56,10 +64,18 @@ impl DataType {
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
             DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
             DataType::String => Box::new(StringSerializer {}),
-            DataType::Struct(fields) => Box::new(StructSerializer {
-                fields: fields.to_vec(),
-            }),
+            DataType::Struct(fields) => Box::new(StructSerializer::create(fields, null_display)),
             _ => todo!(),
+        };
+
+        // The serializers render nulls as DEFAULT_NULL_DISPLAY themselves.
+        if null_display == DEFAULT_NULL_DISPLAY {
+            return inner;
         }
+
+        Box::new(NullDisplaySerializer {
+            inner,
+            null_display: null_display.to_owned(),
+        })
     }
 }
@@ -18,15 +18,44 @@ use crate::prelude::DataColumn;
 use crate::prelude::DataValue;
 use crate::TypeSerializer;
 
-pub struct NullSerializer {}
+pub struct NullSerializer {
+    pub null_display: String,
+}
 
 impl TypeSerializer for NullSerializer {
     fn serialize_value(&self, _value: &DataValue) -> Result<String> {
-        Ok("NULL".to_owned())
+        Ok(self.null_display.clone())
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let result: Vec<String> = vec![self.null_display.clone(); column.len()];
+        Ok(result)
+    }
+}
+
+/// Renders the nulls of the inner serializer as `null_display`.
+pub struct NullDisplaySerializer {
+    pub inner: Box<dyn TypeSerializer>,
+    pub null_display: String,
+}
+
+impl TypeSerializer for NullDisplaySerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if value.is_null() {
+            Ok(self.null_display.clone())
+        } else {
+            self.inner.serialize_value(value)
+        }
     }
 
     fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
-        let result: Vec<String> = vec!["NULL".to_owned(); column.len()];
+        let mut result = self.inner.serialize_column(column)?;
+        let array = column.to_array()?;
+        for (row, value) in result.iter_mut().enumerate() {
+            if array.is_null(row) {
+                *value = self.null_display.clone();
+            }
+        }
         Ok(result)
     }
 }
@@ -19,6 +19,22 @@ use crate::prelude::*;
 
 pub struct StructSerializer {
     pub fields: Vec<DataField>,
+    /// The serializers of the fields, they render the nested nulls.
+    pub serializers: Vec<Box<dyn TypeSerializer>>,
+}
+
+impl StructSerializer {
+    pub fn create(fields: &[DataField], null_display: &str) -> Self {
+        let serializers = fields
+            .iter()
+            .map(|field| field.data_type().create_text_serializer(null_display))
+            .collect();
+
+        Self {
+            fields: fields.to_vec(),
+            serializers,
+        }
+    }
 }
 
 impl TypeSerializer for StructSerializer {
@@ -28,23 +44,24 @@ impl TypeSerializer for StructSerializer {
             res.push('(');
             let mut first = true;
             vals.iter()
-                .zip(self.fields.iter())
-                .for_each(|(val, field)| {
+                .zip(self.fields.iter().zip(self.serializers.iter()))
+                .for_each(|(val, (field, serializer))| {
                     if !first {
                         res.push(',');
                     }
                     first = false;
 
                     let data_type = field.data_type();
-                    let serializer = data_type.create_serializer();
                     let s = serializer.serialize_value(val).unwrap();
-                    if matches!(
-                        data_type,
-                        DataType::String
-                            | DataType::Date16
-                            | DataType::Date32
-                            | DataType::DateTime32(_)
-                    ) {
+                    if !val.is_null()
+                        && matches!(
+                            data_type,
+                            DataType::String
+                                | DataType::Date16
+                                | DataType::Date32
+                                | DataType::DateTime32(_)
+                        )
+                    {
                         res.push_str(&format!("'{}'", s));
                     } else {
                         res.push_str(&s);
@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+fn nulls_of(column: &DataColumn) -> Result<Vec<bool>> {
+    (0..column.len())
+        .map(|i| Ok(column.try_get(i)?.is_null()))
+        .collect()
+}
+
+#[test]
+fn test_apply_validities_merges_own_nulls() -> Result<()> {
+    // The column has its own null, e.g. produced by the function itself.
+    let column: DataColumn = Series::new(vec![Some(1i64), None, Some(3), Some(4)]).into();
+    // The argument nulls.
+    let arg: DataColumn = Series::new(vec![Some(1i64), Some(2), None, Some(4)]).into();
+
+    let column = column.apply_validities(&[arg.get_validity()])?;
+    assert_eq!(nulls_of(&column)?, vec![false, true, true, false]);
+    assert_eq!(column.try_get(0)?, DataValue::Int64(Some(1)));
+    assert_eq!(column.try_get(3)?, DataValue::Int64(Some(4)));
+    Ok(())
+}
+
+#[test]
+fn test_apply_validities_of_several_args() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some(1i64), None, Some(3), Some(4)]).into();
+    let arg1: DataColumn = Series::new(vec![None, Some(2i64), Some(3), Some(4)]).into();
+    let arg2: DataColumn = Series::new(vec![Some(1i64), Some(2), Some(3), None]).into();
+    let valid = DataColumn::Constant(DataValue::Int64(Some(1)), 4);
+
+    let validities = [
+        arg1.get_validity(),
+        arg2.get_validity(),
+        valid.get_validity(),
+    ];
+    let column = column.apply_validities(&validities)?;
+    assert_eq!(nulls_of(&column)?, vec![true, true, false, true]);
+    Ok(())
+}
+
+#[test]
+fn test_apply_validities_to_constant() -> Result<()> {
+    let arg: DataColumn = Series::new(vec![Some(1i64), None, Some(3)]).into();
+
+    // A valid constant takes the argument nulls.
+    let column = DataColumn::Constant(DataValue::Int64(Some(7)), 3);
+    let column = column.apply_validities(&[arg.get_validity()])?;
+    assert_eq!(nulls_of(&column)?, vec![false, true, false]);
+
+    // A null constant stays all null.
+    let column = DataColumn::Constant(DataValue::Int64(None), 3);
+    let column = column.apply_validities(&[arg.get_validity()])?;
+    assert_eq!(nulls_of(&column)?, vec![true, true, true]);
+
+    // An all-null argument nulls the whole column.
+    let column: DataColumn = Series::new(vec![Some(1i64), Some(2), Some(3)]).into();
+    let all_null = DataColumn::Constant(DataValue::Int64(None), 3);
+    let column = column.apply_validities(&[all_null.get_validity()])?;
+    assert_eq!(nulls_of(&column)?, vec![true, true, true]);
+    Ok(())
+}
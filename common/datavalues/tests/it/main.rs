@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod arrays;
+mod columns;
 mod data_array_filter;
 mod types;
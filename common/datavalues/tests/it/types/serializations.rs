@@ -107,3 +107,36 @@ fn test_serializers() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_serializers_null_display() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some(1i32), None, Some(3i32)]).into();
+    let data_type = DataType::Struct(vec![
+        DataField::new("item_0", DataType::Int32, true),
+        DataField::new("item_1", DataType::String, true),
+    ]);
+    let value = DataValue::Struct(vec![DataValue::Int32(Some(1)), DataValue::String(None)]);
+
+    // NULL by default.
+    let serializer = DataType::Int32.create_serializer();
+    assert_eq!(serializer.serialize_value(&DataValue::Int32(None))?, "NULL");
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "1".to_owned(),
+        "NULL".to_owned(),
+        "3".to_owned()
+    ]);
+    let serializer = data_type.create_serializer();
+    assert_eq!(serializer.serialize_value(&value)?, "(1,NULL)");
+
+    let serializer = DataType::Int32.create_text_serializer("\\N");
+    assert_eq!(serializer.serialize_value(&DataValue::Int32(None))?, "\\N");
+    assert_eq!(serializer.serialize_column(&column)?, vec![
+        "1".to_owned(),
+        "\\N".to_owned(),
+        "3".to_owned()
+    ]);
+    let serializer = data_type.create_text_serializer("\\N");
+    assert_eq!(serializer.serialize_value(&value)?, "(1,\\N)");
+
+    Ok(())
+}
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::Result;
 
 use crate::prelude::*;
 
@@ -133,6 +134,15 @@ impl Column for BooleanColumn {
         Arc::new(self.clone())
     }
 
+    fn build_selection(&self, out: &mut Vec<u32>) -> Result<()> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| *selected)
+            .for_each(|(row, _)| out.push(row as u32));
+        Ok(())
+    }
+
     unsafe fn get_unchecked(&self, index: usize) -> DataValue {
         DataValue::Boolean(self.values.get_bit(index))
     }
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_arrow::arrow::array::ArrayRef;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::prelude::*;
@@ -69,12 +70,41 @@ pub trait Column: Send + Sync {
     fn as_arrow_array(&self) -> ArrayRef;
     fn slice(&self, offset: usize, length: usize) -> ColumnRef;
 
+    /// Like `slice`, but returns an error if the range is out of the column.
+    /// Columns wrapping other columns slice the inner ones by `try_slice` too.
+    fn try_slice(&self, offset: usize, length: usize) -> Result<ColumnRef> {
+        match offset.checked_add(length) {
+            Some(end) if end <= self.len() => Ok(self.slice(offset, length)),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Cannot slice {} rows at offset {} of a column with {} rows",
+                length,
+                offset,
+                self.len()
+            ))),
+        }
+    }
+
     // Copies each element according offsets parameter.
     // (i-th element should be copied offsets[i] - offsets[i - 1] times.)
     fn replicate(&self, offsets: &[usize]) -> ColumnRef;
 
     fn convert_full_column(&self) -> ColumnRef;
 
+    /// Append the indices of the true rows to `out`, null rows are not selected.
+    /// Only boolean columns (maybe nullable or const) and null columns are supported.
+    fn build_selection(&self, _out: &mut Vec<u32>) -> Result<()> {
+        Err(ErrorCode::BadDataValueType(format!(
+            "Cannot build selection from non-boolean column of type {:?}",
+            self.data_type_id()
+        )))
+    }
+
+    /// Check the internal consistency of the column, e.g. the validity bitmap
+    /// has the same length as the inner column. Used as a debug assertion helper.
+    fn debug_validate(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// # Safety
     /// Assumes that the `index` is smaller than size.
     unsafe fn get_unchecked(&self, index: usize) -> DataValue;
@@ -138,14 +168,16 @@ impl IntoColumn for ArrayRef {
         let size = self.len();
         let validity = self.validity().cloned();
         let column = self.into_column();
-        Arc::new(NullableColumn::new(
+        let column = NullableColumn::new(
             column,
             validity.unwrap_or_else(|| {
                 let mut bm = MutableBitmap::with_capacity(size);
                 bm.extend_constant(size, false);
                 Bitmap::from(bm)
             }),
-        ))
+        );
+        debug_assert!(column.debug_validate().is_ok());
+        Arc::new(column)
     }
 }
 
@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_arrow::arrow::array::ord;
+use common_arrow::arrow::array::ArrayRef;
+use common_exception::Result;
+
+use crate::prelude::*;
+use crate::NullableColumn;
+use crate::TypeID;
+
+/// Compare the `i`-th row of the left column with the `j`-th row of the right column.
+pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
+
+type NullAt = Box<dyn Fn(usize) -> bool + Send + Sync>;
+
+/// Build the comparator of two columns. Null rows are equal to each other and placed before
+/// (`nulls_first`) or after all the non-null rows, the non-null rows are compared by value.
+pub fn build_compare(lhs: &ColumnRef, rhs: &ColumnRef, nulls_first: bool) -> Result<DynComparator> {
+    let lhs = full_column(lhs);
+    let rhs = full_column(rhs);
+
+    if !lhs.is_nullable() && !rhs.is_nullable() {
+        let lhs = lhs.as_arrow_array();
+        let rhs = rhs.as_arrow_array();
+        return Ok(ord::build_compare(lhs.as_ref(), rhs.as_ref())?);
+    }
+
+    let (lhs_null_at, lhs_values) = split_nullable(&lhs);
+    let (rhs_null_at, rhs_values) = split_nullable(&rhs);
+
+    // One side is a NullColumn, the values are never compared.
+    let compare_values = match (lhs_values, rhs_values) {
+        (Some(lhs), Some(rhs)) => ord::build_compare(lhs.as_ref(), rhs.as_ref())?,
+        _ => Box::new(|_, _| Ordering::Equal),
+    };
+
+    let null_ordering = if nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+
+    Ok(Box::new(move |i, j| {
+        match (lhs_null_at(i), rhs_null_at(j)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => null_ordering,
+            (false, true) => null_ordering.reverse(),
+            (false, false) => compare_values(i, j),
+        }
+    }))
+}
+
+fn full_column(column: &ColumnRef) -> ColumnRef {
+    if column.is_const() {
+        column.convert_full_column()
+    } else {
+        column.clone()
+    }
+}
+
+// Returns the null test and the inner values of a column, the values are None for NullColumn.
+fn split_nullable(column: &ColumnRef) -> (NullAt, Option<ArrayRef>) {
+    if column.data_type_id() == TypeID::Null {
+        return (Box::new(|_| true), None);
+    }
+
+    match column.as_any().downcast_ref::<NullableColumn>() {
+        Some(nullable) => {
+            let validity = nullable.ensure_validity().clone();
+            (
+                Box::new(move |row| !validity.get_bit(row)),
+                Some(nullable.inner().as_arrow_array()),
+            )
+        }
+        None => (Box::new(|_| false), Some(column.as_arrow_array())),
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
 
 use crate::prelude::*;
 
@@ -101,6 +102,15 @@ impl Column for ConstColumn {
         self.column.replicate(&[self.length])
     }
 
+    fn build_selection(&self, out: &mut Vec<u32>) -> Result<()> {
+        let mut selected = Vec::with_capacity(1);
+        self.column.build_selection(&mut selected)?;
+        if !selected.is_empty() {
+            out.extend(0..self.length as u32);
+        }
+        Ok(())
+    }
+
     unsafe fn get_unchecked(&self, _index: usize) -> DataValue {
         self.column.get_unchecked(0)
     }
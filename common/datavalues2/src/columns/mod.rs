@@ -19,6 +19,7 @@ mod mutable;
 mod array;
 mod boolean;
 mod column;
+mod compare;
 mod const_;
 mod eq;
 mod null;
@@ -33,6 +34,7 @@ pub use array::*;
 pub use boolean::*;
 pub use builder::*;
 pub use column::*;
+pub use compare::*;
 pub use const_::*;
 pub use mutable::*;
 pub use null::*;
@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 use crate::prelude::*;
 
@@ -44,6 +46,26 @@ impl NullColumn {
             length: array.len(),
         }
     }
+
+    /// Bytes held by the arrow array exported by `as_arrow_array`. An arrow `NullArray`
+    /// allocates no buffer, so it does not grow with the length either.
+    ///
+    /// Memory accounting (e.g. spill thresholds) should use `memory_size`, which is what
+    /// the column holds in the pipeline, the arrow array only lives while exporting.
+    pub fn arrow_memory_size(&self) -> usize {
+        std::mem::size_of::<NullArray>()
+    }
+
+    /// Keep the rows selected by the filter, only the number of selected rows is counted.
+    pub fn filter(&self, filter: &BooleanColumn) -> ColumnRef {
+        let values = filter.values();
+        debug_assert!(
+            values.len() == self.length,
+            "Size of filter must match size of column"
+        );
+
+        Arc::new(Self::new(values.len() - values.null_count()))
+    }
 }
 
 impl Column for NullColumn {
@@ -83,8 +105,26 @@ impl Column for NullColumn {
         Arc::new(NullArray::new_null(ArrowType::Null, self.length))
     }
 
-    fn slice(&self, _offset: usize, length: usize) -> ColumnRef {
-        Arc::new(Self { length })
+    fn slice(&self, offset: usize, length: usize) -> ColumnRef {
+        match self.try_slice(offset, length) {
+            Ok(column) => column,
+            Err(e) => panic!("{}", e.message()),
+        }
+    }
+
+    fn try_slice(&self, offset: usize, length: usize) -> Result<ColumnRef> {
+        match offset.checked_add(length) {
+            Some(end) if end <= self.length => Ok(Arc::new(Self { length })),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Cannot slice {} rows at offset {} of a column with {} rows",
+                length, offset, self.length
+            ))),
+        }
+    }
+
+    // All rows are null, none is selected.
+    fn build_selection(&self, _out: &mut Vec<u32>) -> Result<()> {
+        Ok(())
     }
 
     fn replicate(&self, offsets: &[usize]) -> ColumnRef {
@@ -28,11 +28,17 @@ pub struct MutableNullColumn {
 }
 
 impl MutableNullColumn {
+    /// Append `n` null rows at once.
+    pub fn append_nulls(&mut self, n: usize) {
+        self.length += n;
+    }
+
+    /// Nothing is allocated for null rows, kept for symmetry with the other mutable columns.
+    pub fn reserve(&mut self, _additional: usize) {}
+
     pub fn finish(&mut self) -> NullColumn {
-        self.length = 0;
-        NullColumn {
-            length: self.length,
-        }
+        let length = std::mem::take(&mut self.length);
+        NullColumn { length }
     }
 }
 
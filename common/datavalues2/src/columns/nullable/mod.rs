@@ -15,6 +15,8 @@
 use common_arrow::arrow::array::*;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 mod mutable;
 use std::sync::Arc;
@@ -50,7 +52,7 @@ impl Column for NullableColumn {
 
     fn data_type(&self) -> DataTypePtr {
         let nest = self.column.data_type();
-        Arc::new(NullableType::create(nest))
+        wrap_nullable(&nest)
     }
 
     fn is_nullable(&self) -> bool {
@@ -89,6 +91,15 @@ impl Column for NullableColumn {
         })
     }
 
+    fn try_slice(&self, offset: usize, length: usize) -> Result<ColumnRef> {
+        // The validity has the same length as the column, it is in range if the column is.
+        let column = self.column.try_slice(offset, length)?;
+        Ok(Arc::new(Self {
+            column,
+            validity: self.validity.clone().slice(offset, length),
+        }))
+    }
+
     fn replicate(&self, offsets: &[usize]) -> ColumnRef {
         debug_assert!(
             offsets.len() == self.len(),
@@ -121,6 +132,28 @@ impl Column for NullableColumn {
         })
     }
 
+    fn build_selection(&self, out: &mut Vec<u32>) -> Result<()> {
+        let mut selected = Vec::with_capacity(self.len());
+        self.column.build_selection(&mut selected)?;
+        out.extend(
+            selected
+                .into_iter()
+                .filter(|row| self.validity.get_bit(*row as usize)),
+        );
+        Ok(())
+    }
+
+    fn debug_validate(&self) -> Result<()> {
+        if self.column.len() != self.validity.len() {
+            return Err(ErrorCode::BadDataArrayLength(format!(
+                "NullableColumn inner length {} doesn't match the validity length {}",
+                self.column.len(),
+                self.validity.len()
+            )));
+        }
+        self.column.debug_validate()
+    }
+
     unsafe fn get_unchecked(&self, _index: usize) -> DataValue {
         self.column.get_unchecked(0)
     }
@@ -17,11 +17,12 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::MutableBitmap;
 
+use crate::wrap_nullable;
+use crate::Column;
 use crate::ColumnRef;
 use crate::DataTypePtr;
 use crate::MutableColumn;
 use crate::NullableColumn;
-use crate::NullableType;
 
 pub struct MutableNullableColumn {
     bitmap: MutableBitmap,
@@ -35,7 +36,7 @@ impl MutableNullableColumn {
         Self {
             bitmap: MutableBitmap::with_capacity(0),
             values,
-            data_type: Arc::new(NullableType::create(inner_type)),
+            data_type: wrap_nullable(&inner_type),
         }
     }
 }
@@ -56,7 +57,9 @@ impl MutableColumn for MutableNullableColumn {
     fn as_column(&mut self) -> ColumnRef {
         let column = self.values.as_column();
         let bitmap = std::mem::take(&mut self.bitmap);
-        Arc::new(NullableColumn::new(column, bitmap.into()))
+        let column = NullableColumn::new(column, bitmap.into());
+        debug_assert!(column.debug_validate().is_ok());
+        Arc::new(column)
     }
 
     #[inline]
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_arrow::arrow::array::*;
+use common_exception::Result;
 
 use crate::prelude::*;
 
@@ -102,6 +103,19 @@ impl Column for StructColumn {
         })
     }
 
+    fn try_slice(&self, offset: usize, length: usize) -> Result<ColumnRef> {
+        let values = self
+            .values
+            .iter()
+            .map(|v| v.try_slice(offset, length))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(Self {
+            values,
+            data_type: self.data_type.clone(),
+        }))
+    }
+
     unsafe fn get_unchecked(&self, index: usize) -> DataValue {
         let values = self.values.iter().map(|v| v.get_unchecked(index)).collect();
         DataValue::Struct(values)
@@ -198,6 +198,117 @@ impl DataValue {
     }
 }
 
+macro_rules! json_to_integer {
+    ($value: expr, $as_fn: ident, $ty: ty, $variant: ident, $data_type: expr) => {{
+        let v = $value.$as_fn().ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "Cannot convert json value {} to {:?}",
+                $value, $data_type
+            ))
+        })?;
+        let v = <$ty>::try_from(v).map_err(|_| {
+            ErrorCode::BadDataValueType(format!("Json value {} overflows {:?}", $value, $data_type))
+        })?;
+        Ok(DataValue::$variant(v.into()))
+    }};
+}
+
+impl DataValue {
+    /// Convert a json value into the DataValue of the given data type.
+    /// Json `null` is only accepted by Null and Nullable types.
+    pub fn from_json(value: &serde_json::Value, data_type: &DataTypePtr) -> Result<DataValue> {
+        let type_id = data_type.data_type_id();
+        if value.is_null() {
+            return match type_id {
+                TypeID::Null | TypeID::Nullable => Ok(DataValue::Null),
+                _ => Err(ErrorCode::BadDataValueType(format!(
+                    "Cannot convert json null to non-nullable type {:?}",
+                    data_type
+                ))),
+            };
+        }
+
+        let bad_value = || {
+            ErrorCode::BadDataValueType(format!(
+                "Cannot convert json value {} to {:?}",
+                value, data_type
+            ))
+        };
+
+        match type_id {
+            TypeID::Nullable => Self::from_json(value, &remove_nullable(data_type)),
+            TypeID::Boolean => value
+                .as_bool()
+                .map(DataValue::Boolean)
+                .ok_or_else(bad_value),
+            TypeID::Int8 => json_to_integer!(value, as_i64, i8, Int64, data_type),
+            TypeID::Int16 => json_to_integer!(value, as_i64, i16, Int64, data_type),
+            TypeID::Int32 | TypeID::Date32 => {
+                json_to_integer!(value, as_i64, i32, Int64, data_type)
+            }
+            TypeID::Int64 | TypeID::Interval => {
+                value.as_i64().map(DataValue::Int64).ok_or_else(bad_value)
+            }
+            TypeID::UInt8 => json_to_integer!(value, as_u64, u8, UInt64, data_type),
+            TypeID::UInt16 | TypeID::Date16 => {
+                json_to_integer!(value, as_u64, u16, UInt64, data_type)
+            }
+            TypeID::UInt32 | TypeID::DateTime32 => {
+                json_to_integer!(value, as_u64, u32, UInt64, data_type)
+            }
+            TypeID::UInt64 | TypeID::DateTime64 => {
+                value.as_u64().map(DataValue::UInt64).ok_or_else(bad_value)
+            }
+            TypeID::Float32 | TypeID::Float64 => {
+                value.as_f64().map(DataValue::Float64).ok_or_else(bad_value)
+            }
+            TypeID::String => value
+                .as_str()
+                .map(|v| DataValue::String(v.as_bytes().to_vec()))
+                .ok_or_else(bad_value),
+            TypeID::Array => {
+                let array_type: &ArrayType = data_type.as_any().downcast_ref().unwrap();
+                let values = value.as_array().ok_or_else(bad_value)?;
+                let values = values
+                    .iter()
+                    .map(|v| Self::from_json(v, array_type.inner_type()))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataValue::Array(values))
+            }
+            TypeID::Struct => {
+                let struct_type: &StructType = data_type.as_any().downcast_ref().unwrap();
+                let values = value.as_array().ok_or_else(bad_value)?;
+                if values.len() != struct_type.types().len() {
+                    return Err(bad_value());
+                }
+                let values = values
+                    .iter()
+                    .zip(struct_type.types().iter())
+                    .map(|(v, ty)| Self::from_json(v, ty))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataValue::Struct(values))
+            }
+            TypeID::Null => Err(bad_value()),
+        }
+    }
+
+    /// Convert the DataValue into a json value, `DataValue::Null` is json `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            DataValue::Null => serde_json::Value::Null,
+            DataValue::Boolean(v) => serde_json::Value::from(*v),
+            DataValue::Int64(v) => serde_json::Value::from(*v),
+            DataValue::UInt64(v) => serde_json::Value::from(*v),
+            // NaN and infinity are not valid json numbers, they become null
+            DataValue::Float64(v) => serde_json::Value::from(*v),
+            DataValue::String(v) => serde_json::Value::from(String::from_utf8_lossy(v)),
+            DataValue::Array(v) | DataValue::Struct(v) => {
+                serde_json::Value::Array(v.iter().map(DataValue::to_json).collect())
+            }
+        }
+    }
+}
+
 // Did not use std::convert:TryFrom
 // Because we do not need custom type error.
 pub trait DFTryFrom<T>: Sized {
@@ -147,12 +147,14 @@ pub fn from_arrow_field(f: &ArrowField) -> DataTypePtr {
 
     let is_nullable = f.is_nullable();
     if is_nullable {
-        Arc::new(NullableType::create(ty))
+        wrap_nullable(&ty)
     } else {
         ty
     }
 }
 
+/// Make the type nullable, a type that is nullable already(including `Null`) is returned as is.
+/// Prefer it to `NullableType::create`, which never checks for a nullable inside a nullable.
 pub fn wrap_nullable(data_type: &DataTypePtr) -> DataTypePtr {
     if matches!(data_type.data_type_id(), TypeID::Nullable | TypeID::Null) {
         return data_type.clone();
@@ -167,3 +169,13 @@ pub fn unwrap_nullable(data_type: &DataTypePtr) -> DataTypePtr {
     }
     data_type.clone()
 }
+
+/// Unwrap all the nullable layers and return the base type.
+/// Nullable inside nullable is invalid, it's unwrapped as well instead of leaking out.
+pub fn remove_nullable(data_type: &DataTypePtr) -> DataTypePtr {
+    let mut data_type = data_type.clone();
+    while matches!(data_type.data_type_id(), TypeID::Nullable) {
+        data_type = unwrap_nullable(&data_type);
+    }
+    data_type
+}
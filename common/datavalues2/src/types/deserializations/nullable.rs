@@ -21,9 +21,31 @@ use crate::ColumnRef;
 use crate::NullableColumn;
 use crate::TypeDeserializer;
 
+/// The texts read as null by default, e.g. from CSV.
+/// An empty string is not null, tokens like empty or `NULL` are opted in by `with_null_tokens`.
+pub const DEFAULT_NULL_TOKENS: [&[u8]; 1] = [b"\\N"];
+
 pub struct NullableDeserializer {
     pub inner: Box<dyn TypeDeserializer>,
     pub bitmap: MutableBitmap,
+    /// The texts read as null by `de_text`.
+    pub null_tokens: Vec<Vec<u8>>,
+}
+
+impl NullableDeserializer {
+    pub fn create(inner: Box<dyn TypeDeserializer>, capacity: usize) -> Self {
+        Self {
+            inner,
+            bitmap: MutableBitmap::with_capacity(capacity),
+            null_tokens: DEFAULT_NULL_TOKENS.iter().map(|t| t.to_vec()).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_null_tokens(mut self, null_tokens: &[&[u8]]) -> Self {
+        self.null_tokens = null_tokens.iter().map(|t| t.to_vec()).collect();
+        self
+    }
 }
 
 impl TypeDeserializer for NullableDeserializer {
@@ -47,6 +69,15 @@ impl TypeDeserializer for NullableDeserializer {
     }
 
     fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if self
+            .null_tokens
+            .iter()
+            .any(|token| token.as_slice() == reader)
+        {
+            self.de_null();
+            return Ok(());
+        }
+
         self.inner.de_text(reader)?;
         self.bitmap.push(true);
         Ok(())
@@ -26,6 +26,7 @@ mod string;
 mod struct_;
 
 pub use boolean::*;
+pub use common_io::prelude::DEFAULT_NULL_DISPLAY;
 pub use date::*;
 pub use date_time::*;
 pub use null::*;
@@ -17,17 +17,37 @@ use common_exception::Result;
 use crate::prelude::DataValue;
 use crate::ColumnRef;
 use crate::TypeSerializer;
+use crate::DEFAULT_NULL_DISPLAY;
 
-#[derive(Clone, Debug, Default)]
-pub struct NullSerializer {}
+#[derive(Clone, Debug)]
+pub struct NullSerializer {
+    /// The text rendered for null values.
+    pub null_display: String,
+}
+
+impl NullSerializer {
+    #[must_use]
+    pub fn with_null_display(mut self, null_display: &str) -> Self {
+        self.null_display = null_display.to_owned();
+        self
+    }
+}
+
+impl Default for NullSerializer {
+    fn default() -> Self {
+        Self {
+            null_display: DEFAULT_NULL_DISPLAY.to_owned(),
+        }
+    }
+}
 
 impl TypeSerializer for NullSerializer {
     fn serialize_value(&self, _value: &DataValue) -> Result<String> {
-        Ok("NULL".to_owned())
+        Ok(self.null_display.clone())
     }
 
     fn serialize_column(&self, column: &ColumnRef) -> Result<Vec<String>> {
-        let result: Vec<String> = vec!["NULL".to_owned(); column.len()];
+        let result: Vec<String> = vec![self.null_display.clone(); column.len()];
         Ok(result)
     }
 }
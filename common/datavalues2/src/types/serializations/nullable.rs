@@ -20,15 +20,34 @@ use crate::ColumnRef;
 use crate::NullableColumn;
 use crate::Series;
 use crate::TypeSerializer;
+use crate::DEFAULT_NULL_DISPLAY;
 
 pub struct NullableSerializer {
     pub inner: Box<dyn TypeSerializer>,
+    /// The text rendered for null values, render `\N` to read the output back as null
+    /// by the default `NullableDeserializer`.
+    pub null_display: String,
+}
+
+impl NullableSerializer {
+    pub fn create(inner: Box<dyn TypeSerializer>) -> Self {
+        Self {
+            inner,
+            null_display: DEFAULT_NULL_DISPLAY.to_owned(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_null_display(mut self, null_display: &str) -> Self {
+        self.null_display = null_display.to_owned();
+        self
+    }
 }
 
 impl TypeSerializer for NullableSerializer {
     fn serialize_value(&self, value: &DataValue) -> Result<String> {
         if value.is_null() {
-            Ok("NULL".to_owned())
+            Ok(self.null_display.clone())
         } else {
             self.inner.serialize_value(value)
         }
@@ -41,7 +60,7 @@ impl TypeSerializer for NullableSerializer {
 
         (0..rows).for_each(|row| {
             if column.null_at(row) {
-                res[row] = "NULL".to_owned();
+                res[row] = self.null_display.clone();
             }
         });
         Ok(res)
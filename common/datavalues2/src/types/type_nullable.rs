@@ -36,6 +36,11 @@ impl NullableType {
     pub fn inner_type(&self) -> &DataTypePtr {
         &self.inner
     }
+
+    /// The fully unwrapped non-nullable type.
+    pub fn remove_nullable(&self) -> DataTypePtr {
+        remove_nullable(&self.inner)
+    }
 }
 
 #[typetag::serde]
@@ -62,16 +67,14 @@ impl DataType for NullableType {
     }
 
     fn create_serializer(&self) -> Box<dyn TypeSerializer> {
-        Box::new(NullableSerializer {
-            inner: self.inner.create_serializer(),
-        })
+        Box::new(NullableSerializer::create(self.inner.create_serializer()))
     }
 
     fn create_deserializer(&self, capacity: usize) -> Box<dyn TypeDeserializer> {
-        Box::new(NullableDeserializer {
-            inner: self.inner.create_deserializer(capacity),
-            bitmap: MutableBitmap::with_capacity(capacity),
-        })
+        Box::new(NullableDeserializer::create(
+            self.inner.create_deserializer(capacity),
+            capacity,
+        ))
     }
 
     fn create_constant_column(
@@ -84,7 +87,7 @@ impl DataType for NullableType {
         if self.inner.data_type_id() == TypeID::Null {
             return Ok(Arc::new(NullColumn::new(size)));
         }
-        if self.inner.data_type_id() == TypeID::Null {
+        if self.inner.is_nullable() {
             return Result::Err(ErrorCode::BadDataValueType(
                 "Nullable type can't be inside nullable type".to_string(),
             ));
@@ -101,6 +104,21 @@ impl DataType for NullableType {
     }
 
     fn create_column(&self, data: &[DataValue]) -> common_exception::Result<ColumnRef> {
+        if self.inner.data_type_id() == TypeID::Null {
+            return Ok(Arc::new(NullColumn::new(data.len())));
+        }
+
+        // All null, no need to push the rows one by one.
+        if data.iter().all(|v| v.is_null()) {
+            let mut bitmap = MutableBitmap::with_capacity(data.len());
+            bitmap.extend_constant(data.len(), false);
+            let column = self
+                .inner
+                .create_constant_column(&self.inner.default_value(), data.len())?
+                .convert_full_column();
+            return Ok(Arc::new(NullableColumn::new(column, bitmap.into())));
+        }
+
         let mut res = Vec::with_capacity(data.len());
         let mut bitmap = MutableBitmap::with_capacity(data.len());
 
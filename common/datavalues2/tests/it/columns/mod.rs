@@ -13,5 +13,7 @@
 // limitations under the License.
 
 mod boolean;
+mod null;
+mod nullable;
 mod primitive;
 mod string;
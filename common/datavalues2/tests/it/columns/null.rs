@@ -0,0 +1,99 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[test]
+fn test_null_column_try_slice() -> Result<()> {
+    let column = NullColumn::new(10);
+
+    let sliced = column.try_slice(8, 2)?;
+    assert_eq!(sliced.len(), 2);
+    assert!(sliced.only_null());
+
+    let error = column.try_slice(8, 5).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_arguments_code());
+    assert_eq!(
+        error.message(),
+        "Cannot slice 5 rows at offset 8 of a column with 10 rows"
+    );
+
+    assert!(column.try_slice(usize::MAX, 2).is_err());
+
+    // A wrapped null column is sliced by `try_slice` too.
+    let validity = Bitmap::from(vec![false; 10]);
+    let nullable = NullableColumn::new(Arc::new(column), validity);
+    assert_eq!(nullable.try_slice(8, 2)?.len(), 2);
+    let error = nullable.try_slice(usize::MAX, 2).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_arguments_code());
+    Ok(())
+}
+
+#[test]
+fn test_null_column_filter() {
+    let column = NullColumn::new(4);
+    let filter: BooleanColumn = NewColumn::new_from_slice(&[true, false, true, true]);
+
+    let filtered = column.filter(&filter);
+    assert_eq!(filtered.len(), 3);
+    assert!(filtered.only_null());
+}
+
+#[test]
+fn test_null_column_memory_size() {
+    let small = NullColumn::new(1);
+    let large = NullColumn::new(1_000_000);
+
+    // No buffer is allocated for the rows of a null column, neither of its arrow array.
+    assert_eq!(small.memory_size(), large.memory_size());
+    assert_eq!(small.arrow_memory_size(), large.arrow_memory_size());
+    assert!(large.arrow_memory_size() > 0);
+}
+
+#[test]
+fn test_mutable_null_column() {
+    let mut builder = MutableNullColumn::default();
+    for _ in 0..5 {
+        builder.append_null();
+    }
+    builder.append_default();
+
+    let column = builder.as_column();
+    assert_eq!(column.len(), 6);
+    assert!(column.only_null());
+
+    // The builder is reset after finishing.
+    assert_eq!(builder.finish().len(), 0);
+}
+
+#[test]
+fn test_mutable_null_column_append_nulls() {
+    let mut builder = MutableNullColumn::default();
+    builder.reserve(1024);
+    builder.append_nulls(1000);
+    builder.append_null();
+
+    assert_eq!(builder.finish().len(), 1001);
+}
+
+#[test]
+#[should_panic]
+fn test_null_column_slice_out_of_range() {
+    NullColumn::new(10).slice(8, 5);
+}
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+#[test]
+fn test_nullable_column_debug_validate() {
+    let inner = Series::from_data(vec![1i32, 2, 3]);
+
+    let column = NullableColumn::new(inner.clone(), Bitmap::from(vec![true, false, true]));
+    assert!(column.debug_validate().is_ok());
+
+    // The bitmap is shorter than the inner column.
+    let column = NullableColumn::new(inner, Bitmap::from(vec![true, false]));
+    let err = column.debug_validate().unwrap_err();
+    assert_eq!(err.code(), ErrorCode::bad_data_array_length_code());
+
+    let column = NullColumn::new(10);
+    assert!(column.debug_validate().is_ok());
+}
+
+#[test]
+fn test_nullable_column_build_selection() -> Result<()> {
+    let inner = Series::from_data(vec![true, true, false, true, false]);
+    let column = NullableColumn::new(
+        inner.clone(),
+        Bitmap::from(vec![true, false, true, true, false]),
+    );
+
+    // Null rows are never selected, even if the value behind is true.
+    let mut selection = vec![];
+    column.build_selection(&mut selection)?;
+    assert_eq!(selection, vec![0, 3]);
+
+    let mut selection = vec![];
+    inner.build_selection(&mut selection)?;
+    assert_eq!(selection, vec![0, 1, 3]);
+
+    let mut selection = vec![];
+    NullColumn::new(5).build_selection(&mut selection)?;
+    assert!(selection.is_empty());
+
+    let mut selection = vec![];
+    ConstColumn::new(Series::from_data(vec![true]), 3).build_selection(&mut selection)?;
+    assert_eq!(selection, vec![0, 1, 2]);
+
+    let mut selection = vec![];
+    let err = Series::from_data(vec![1i32, 2, 3])
+        .build_selection(&mut selection)
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::bad_data_value_type_code());
+
+    Ok(())
+}
+
+#[test]
+fn test_nullable_column_compare() -> Result<()> {
+    let inner = Series::from_data(vec![3i32, 1, 2, 5, 4]);
+    let column: ColumnRef = Arc::new(NullableColumn::new(
+        inner,
+        Bitmap::from(vec![true, false, true, false, true]),
+    ));
+
+    let sort = |nulls_first: bool| -> Result<Vec<usize>> {
+        let compare = build_compare(&column, &column, nulls_first)?;
+        let mut indices = (0..column.len()).collect::<Vec<_>>();
+        indices.sort_by(|i, j| compare(*i, *j));
+        Ok(indices)
+    };
+
+    // Rows 1 and 3 are null, the others are 3, 2, 4.
+    assert_eq!(sort(true)?, vec![1, 3, 2, 0, 4]);
+    assert_eq!(sort(false)?, vec![2, 0, 4, 1, 3]);
+
+    // Compare with a NullColumn and a non-nullable column.
+    let nulls: ColumnRef = Arc::new(NullColumn::new(5));
+    let values = Series::from_data(vec![1i32, 2, 3, 4, 5]);
+
+    let compare = build_compare(&nulls, &values, true)?;
+    assert_eq!(compare(0, 0), Ordering::Less);
+    let compare = build_compare(&nulls, &values, false)?;
+    assert_eq!(compare(0, 0), Ordering::Greater);
+    let compare = build_compare(&column, &nulls, false)?;
+    assert_eq!(compare(1, 1), Ordering::Equal);
+    let compare = build_compare(&column, &values, false)?;
+    assert_eq!(compare(0, 2), Ordering::Equal);
+    assert_eq!(compare(2, 2), Ordering::Less);
+
+    Ok(())
+}
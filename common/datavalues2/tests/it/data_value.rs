@@ -0,0 +1,110 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_data_value_json_round_trip() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        data_type: DataTypePtr,
+        value: DataValue,
+        json: serde_json::Value,
+    }
+
+    let nullable_int32: DataTypePtr = Arc::new(NullableType::create(Int32Type::arc()));
+    let tests = vec![
+        Test {
+            name: "null",
+            data_type: nullable_int32.clone(),
+            value: DataValue::Null,
+            json: json!(null),
+        },
+        Test {
+            name: "nullable int",
+            data_type: nullable_int32,
+            value: DataValue::Int64(-3),
+            json: json!(-3),
+        },
+        Test {
+            name: "int",
+            data_type: Int8Type::arc(),
+            value: DataValue::Int64(127),
+            json: json!(127),
+        },
+        Test {
+            name: "unsigned int",
+            data_type: UInt64Type::arc(),
+            value: DataValue::UInt64(u64::MAX),
+            json: json!(u64::MAX),
+        },
+        Test {
+            name: "float",
+            data_type: Float64Type::arc(),
+            value: DataValue::Float64(1.5),
+            json: json!(1.5),
+        },
+        Test {
+            name: "string",
+            data_type: StringType::arc(),
+            value: DataValue::String(b"databend".to_vec()),
+            json: json!("databend"),
+        },
+    ];
+
+    for test in tests {
+        assert_eq!(test.value.to_json(), test.json, "case: {}", test.name);
+        let value = DataValue::from_json(&test.json, &test.data_type)?;
+        assert_eq!(value, test.value, "case: {}", test.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_value_from_json_error() -> Result<()> {
+    // null for a non-nullable type
+    let result = DataValue::from_json(&json!(null), &Int32Type::arc());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::bad_data_value_type_code()
+    );
+
+    // overflow the target int width
+    let result = DataValue::from_json(&json!(128), &Int8Type::arc());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::bad_data_value_type_code()
+    );
+    let result = DataValue::from_json(&json!(-1), &UInt8Type::arc());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::bad_data_value_type_code()
+    );
+
+    // type mismatch
+    let result = DataValue::from_json(&json!("1"), &Int64Type::arc());
+    assert_eq!(
+        result.unwrap_err().code(),
+        ErrorCode::bad_data_value_type_code()
+    );
+
+    Ok(())
+}
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod columns;
+mod data_value;
 mod types;
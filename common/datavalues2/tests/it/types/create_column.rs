@@ -119,3 +119,23 @@ fn test_create_constant() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_create_all_null_nullable_column() -> Result<()> {
+    let rows = 1_000_000;
+    let data = vec![DataValue::Null; rows];
+
+    let nullable = NullableType::create(Int32Type::arc());
+    let column = nullable.create_column(&data)?;
+    assert_eq!(column.len(), rows);
+    assert!(column.only_null());
+    assert!(!column.is_const());
+
+    // The inner type is Null, the rows are not materialized at all.
+    let nullable = NullableType::create(Arc::new(NullType {}));
+    let column = nullable.create_column(&data)?;
+    assert_eq!(column.len(), rows);
+    assert_eq!(column.data_type_id(), TypeID::Null);
+
+    Ok(())
+}
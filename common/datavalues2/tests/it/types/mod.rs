@@ -13,5 +13,6 @@
 // limitations under the License.
 
 mod create_column;
+mod nullable;
 mod serializations;
 mod wrapper;
@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_datavalues2::prelude::*;
+use common_exception::ErrorCode;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_remove_nullable() {
+    let nullable = NullableType::create(Int32Type::arc());
+    assert_eq!(nullable.remove_nullable().data_type_id(), TypeID::Int32);
+
+    let nullable: DataTypePtr = Arc::new(nullable);
+    assert_eq!(remove_nullable(&nullable).data_type_id(), TypeID::Int32);
+
+    let int32 = Int32Type::arc();
+    assert_eq!(remove_nullable(&int32).data_type_id(), TypeID::Int32);
+
+    // Invalid nested nullable is unwrapped to the base type as well.
+    let nested: DataTypePtr = Arc::new(NullableType::create(nullable));
+    assert_eq!(remove_nullable(&nested).data_type_id(), TypeID::Int32);
+}
+
+#[test]
+fn test_nullable_deserializer_null_tokens() {
+    let texts: [&[u8]; 5] = [b"a", b"\\N", b"", b"NULL", b"b"];
+
+    // Only \N is read as null by default.
+    let nullable = NullableType::create(StringType::arc());
+    let mut deserializer = nullable.create_deserializer(texts.len());
+    for text in texts {
+        deserializer.de_text(text).unwrap();
+    }
+    let column = deserializer.finish_to_column();
+    let nulls = (0..column.len())
+        .map(|row| column.null_at(row))
+        .collect::<Vec<_>>();
+    assert_eq!(nulls, vec![false, true, false, false, false]);
+
+    // The configured tokens are read as null.
+    let inner = StringType::arc().create_deserializer(texts.len());
+    let mut deserializer =
+        NullableDeserializer::create(inner, texts.len()).with_null_tokens(&[b"\\N", b""]);
+    for text in texts {
+        deserializer.de_text(text).unwrap();
+    }
+    let column = deserializer.finish_to_column();
+    let nulls = (0..column.len())
+        .map(|row| column.null_at(row))
+        .collect::<Vec<_>>();
+    assert_eq!(nulls, vec![false, true, true, false, false]);
+}
+
+#[test]
+fn test_nested_nullable_constant_column() {
+    let nullable: DataTypePtr = Arc::new(NullableType::create(Int32Type::arc()));
+    let nested = NullableType::create(nullable);
+
+    let error = nested
+        .create_constant_column(&DataValue::Int64(1), 3)
+        .unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_data_value_type_code());
+    assert_eq!(
+        error.message(),
+        "Nullable type can't be inside nullable type"
+    );
+}
+
+#[test]
+fn test_wrap_nullable() {
+    let nullable = wrap_nullable(&Int32Type::arc());
+    assert_eq!(nullable.data_type_id(), TypeID::Nullable);
+    assert_eq!(unwrap_nullable(&nullable).data_type_id(), TypeID::Int32);
+
+    // Wrapping twice still makes a single-level nullable.
+    let twice = wrap_nullable(&nullable);
+    assert_eq!(twice.data_type_id(), TypeID::Nullable);
+    assert_eq!(unwrap_nullable(&twice).data_type_id(), TypeID::Int32);
+
+    let null: DataTypePtr = Arc::new(NullType {});
+    assert_eq!(wrap_nullable(&null).data_type_id(), TypeID::Null);
+
+    // A nullable arrow field of the null type is not wrapped twice.
+    let field = ArrowField::new("n", ArrowType::Null, true);
+    let data_type = from_arrow_field(&field);
+    assert_eq!(data_type.data_type_id(), TypeID::Nullable);
+    assert_eq!(unwrap_nullable(&data_type).data_type_id(), TypeID::Null);
+}
@@ -116,3 +116,24 @@ fn test_serializers() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_nullable_serializer_null_display() -> Result<()> {
+    let nullable = NullableType::create(Int32Type::arc());
+    let column =
+        nullable.create_column(&[DataValue::Int64(1), DataValue::Null, DataValue::Int64(3)])?;
+
+    // NULL by default.
+    let serializer = nullable.create_serializer();
+    let expect = vec!["1".to_owned(), "NULL".to_owned(), "3".to_owned()];
+    assert_eq!(serializer.serialize_value(&DataValue::Null)?, "NULL");
+    assert_eq!(serializer.serialize_column(&column)?, expect);
+
+    let serializer =
+        NullableSerializer::create(Int32Type::arc().create_serializer()).with_null_display("\\N");
+    let expect = vec!["1".to_owned(), "\\N".to_owned(), "3".to_owned()];
+    assert_eq!(serializer.serialize_value(&DataValue::Null)?, "\\N");
+    assert_eq!(serializer.serialize_column(&column)?, expect);
+
+    Ok(())
+}
@@ -134,6 +134,9 @@ build_exceptions! {
 
     // network error
     NetworkRequestError(1073),
+
+    // access error
+    ReadOnlyMode(1074),
 }
 
 // Metasvr errors [2001, 3000].
@@ -153,6 +156,8 @@ build_exceptions! {
     TableVersionMismatched(2009),
     UnknownDatabaseId(2010),
     OCCRetryFailure(2011),
+    MetaRetryExhausted(2012),
+    DataCorruption(2013),
 
 
     // KVSrv server error
@@ -184,7 +189,7 @@ build_exceptions! {
 
     // user defined function error.
     IllegalUDFFormat(2601),
-    UnknownUDF(2602),
+    UnknownUdf(2602),
     UdfAlreadyExists(2603),
 
 
@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use http::StatusCode;
+
+use crate::ErrorCode;
+
+impl ErrorCode {
+    /// The HTTP status of the error category, errors not categorized are internal errors.
+    pub fn to_http_status(&self) -> StatusCode {
+        let code = self.code();
+        let is_one_of = |codes: &[u16]| codes.contains(&code);
+
+        if is_one_of(&[
+            ErrorCode::unknown_database_code(),
+            ErrorCode::unknown_table_code(),
+            ErrorCode::unknown_column_code(),
+            ErrorCode::unknown_function_code(),
+            ErrorCode::unknown_aggregate_function_code(),
+            ErrorCode::unknown_table_function_code(),
+            ErrorCode::unknown_setting_code(),
+            ErrorCode::unknown_variable_code(),
+            ErrorCode::unknown_session_code(),
+            ErrorCode::not_found_session_code(),
+            ErrorCode::not_found_cluster_node_code(),
+            ErrorCode::http_not_found_code(),
+            ErrorCode::unknown_user_code(),
+            ErrorCode::unknown_role_code(),
+            ErrorCode::unknown_stage_code(),
+            ErrorCode::unknown_udf_code(),
+            ErrorCode::dal_path_not_found_code(),
+        ]) {
+            StatusCode::NOT_FOUND
+        } else if is_one_of(&[
+            ErrorCode::bad_arguments_code(),
+            ErrorCode::bad_option_code(),
+            ErrorCode::syntax_exception_code(),
+            ErrorCode::semantic_error_code(),
+            ErrorCode::number_arguments_not_match_code(),
+            ErrorCode::illegal_data_type_code(),
+            ErrorCode::str_parse_error_code(),
+            ErrorCode::date_time_parse_error_code(),
+        ]) {
+            StatusCode::BAD_REQUEST
+        } else if is_one_of(&[ErrorCode::authenticate_failure_code()]) {
+            StatusCode::UNAUTHORIZED
+        } else if is_one_of(&[
+            ErrorCode::permission_denied_code(),
+            ErrorCode::management_mode_permission_denied_code(),
+            ErrorCode::read_only_mode_code(),
+        ]) {
+            StatusCode::FORBIDDEN
+        } else if is_one_of(&[ErrorCode::timeout_code()]) {
+            StatusCode::GATEWAY_TIMEOUT
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
@@ -225,6 +225,7 @@ impl From<&tonic::Status> for ErrorCode {
                     },
                 }
             }
+            tonic::Code::Unauthenticated => ErrorCode::AuthenticateFailure(status.message()),
             _ => ErrorCode::UnImplement(status.to_string()),
         }
     }
@@ -14,6 +14,7 @@
 
 pub mod exception;
 mod exception_code;
+mod exception_http;
 mod exception_into;
 
 pub use exception::ErrorCode;
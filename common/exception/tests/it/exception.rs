@@ -14,6 +14,7 @@
 
 use common_exception::ErrorCode;
 use common_exception::SerializedError;
+use http::StatusCode;
 use tonic::Code;
 use tonic::Status;
 
@@ -153,3 +154,39 @@ fn test_from_and_to_status() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_error_code_to_http_status() {
+    assert_eq!(
+        ErrorCode::UnknownTable("t").to_http_status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        ErrorCode::HttpNotFound("page").to_http_status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        ErrorCode::BadArguments("a").to_http_status(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        ErrorCode::SyntaxException("s").to_http_status(),
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        ErrorCode::Timeout("t").to_http_status(),
+        StatusCode::GATEWAY_TIMEOUT
+    );
+    assert_eq!(
+        ErrorCode::PermissionDenied("p").to_http_status(),
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        ErrorCode::ReadOnlyMode("r").to_http_status(),
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        ErrorCode::LogicalError("l").to_http_status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
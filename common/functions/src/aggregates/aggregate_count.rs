@@ -102,6 +102,11 @@ impl AggregateFunction for AggregateCountFunction {
             return Ok(());
         }
 
+        // A NULL column has no validity bitmap, but none of its rows is counted.
+        if arrays[0].data_type() == &DataType::Null {
+            return Ok(());
+        }
+
         match arrays[0].get_array_ref().validity() {
             None => {
                 for place in places.iter() {
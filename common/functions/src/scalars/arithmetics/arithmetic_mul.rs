@@ -93,6 +93,7 @@ impl ArithmeticMulFunction {
             FunctionFeatures::default()
                 .deterministic()
                 .monotonicity()
+                .commutative()
                 .num_arguments(2),
         )
     }
@@ -164,6 +164,7 @@ impl ArithmeticPlusFunction {
             FunctionFeatures::default()
                 .deterministic()
                 .monotonicity()
+                .commutative()
                 .num_arguments(2),
         )
     }
@@ -65,6 +65,14 @@ impl Function for ComparisonFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        // Comparing with an all null column, the result is all null boolean.
+        // An array-backed Null column carries no validity bitmap, so check the type too.
+        if columns.iter().any(|column| {
+            column.data_type() == &DataType::Null || column.column().get_validity().all_null()
+        }) {
+            return Ok(DataColumn::Constant(DataValue::Boolean(None), input_rows));
+        }
+
         if columns[0].data_type() != columns[1].data_type() {
             let compare_coercion_type =
                 compare_coercion(columns[0].data_type(), columns[1].data_type())?;
@@ -33,6 +33,7 @@ impl ComparisonNotEqFunction {
                 .deterministic()
                 .negative_function("=")
                 .bool_function()
+                .commutative()
                 .num_arguments(2),
         )
     }
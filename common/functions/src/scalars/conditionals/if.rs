@@ -17,6 +17,7 @@ use std::fmt;
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnsWithField;
 use common_datavalues::DataTypeAndNullable;
+use common_datavalues::DataValue;
 use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionDescription;
@@ -47,9 +48,9 @@ impl Function for IfFunction {
     }
 
     fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
-        // IF(condition, value_if_true, value_if_false) is nullable if 'value_if_true' is nullable or 'value_if_false' is nullable.
-        // The condition of 'Null' is treated as false. SELECT IF(NULL, "YES", "NO") -> "No"
-        let nullable = args[1].is_nullable() || args[2].is_nullable();
+        // IF(condition, value_if_true, value_if_false) is nullable if any of the arguments is nullable.
+        // The condition of 'Null' yields null. SELECT IF(NULL, "YES", "NO") -> NULL
+        let nullable = args.iter().any(|arg| arg.is_nullable());
 
         let mut aggregate_args = Vec::with_capacity(args.len() - 1);
         for arg in args.iter().skip(1) {
@@ -60,10 +61,24 @@ impl Function for IfFunction {
         Ok(DataTypeAndNullable::create(&data_type, nullable))
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
-        columns[0]
-            .column()
-            .if_then_else(columns[1].column(), columns[2].column())
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let cond = columns[0].column();
+        let cond_validity = cond.get_validity();
+
+        // The condition is all null, the result is all null of the merged branch type.
+        // An array-backed Null condition carries no validity bitmap, so check the type too.
+        if columns[0].data_type() == &DataType::Null || cond_validity.all_null() {
+            let data_type = common_datavalues::aggregate_types(&[
+                columns[1].data_type().clone(),
+                columns[2].data_type().clone(),
+            ])?;
+            let null_value = DataValue::new_from_data_type(&data_type, true);
+            return Ok(DataColumn::Constant(null_value, input_rows));
+        }
+
+        // Only the condition's nulls are masked here, the branch nulls are already picked by if_then_else.
+        let column = cond.if_then_else(columns[1].column(), columns[2].column())?;
+        column.apply_validities(&[cond_validity])
     }
 
     fn passthrough_null(&self) -> bool {
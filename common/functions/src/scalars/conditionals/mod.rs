@@ -14,6 +14,8 @@
 
 mod conditional;
 mod r#if;
+mod nullif;
 
 pub use conditional::ConditionalFunction;
+pub use nullif::NullIfFunction;
 pub use r#if::IfFunction;
@@ -0,0 +1,83 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::columns::DataColumn;
+use common_datavalues::columns::DataColumnValidity;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataTypeAndNullable;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::ComparisonEqFunction;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct NullIfFunction {
+    _display_name: String,
+}
+
+impl NullIfFunction {
+    pub fn try_create_func(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(NullIfFunction {
+            _display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create_func))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for NullIfFunction {
+    fn name(&self) -> &str {
+        "NullIfFunction"
+    }
+
+    fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        // NULLIF(a, b) is always nullable, it yields null when a = b.
+        Ok(DataTypeAndNullable::create(args[0].data_type(), true))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let eq = ComparisonEqFunction::try_create_func("")?.eval(columns, input_rows)?;
+        let eq = eq.to_array()?;
+
+        // The row is kept unless a = b is true. A null comparison (either side is null) keeps a as it is,
+        // so NULLIF(NULL, 1) -> NULL and NULLIF(1, NULL) -> 1.
+        let validity: Bitmap = eq
+            .bool()?
+            .collect_values()
+            .iter()
+            .map(|v| *v != Some(true))
+            .collect();
+
+        let column = columns[0].column().clone();
+        column.apply_validities(&[DataColumnValidity::Array(Some(validity), input_rows)])
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Display for NullIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NULLIF")
+    }
+}
@@ -49,6 +49,8 @@ pub struct FunctionFeatures {
     pub is_bool_func: bool,
     pub is_context_func: bool,
     pub maybe_monotonic: bool,
+    // f(a, b) == f(b, a), the arguments can be reordered freely.
+    pub is_commutative: bool,
     // The number of arguments the function accepts.
     pub num_arguments: usize,
     // (1, 2) means we only accept [1, 2] arguments
@@ -64,6 +66,7 @@ impl FunctionFeatures {
             is_bool_func: false,
             is_context_func: false,
             maybe_monotonic: false,
+            is_commutative: false,
             num_arguments: 0,
             variadic_arguments: None,
         }
@@ -94,6 +97,11 @@ impl FunctionFeatures {
         self
     }
 
+    pub fn commutative(mut self) -> FunctionFeatures {
+        self.is_commutative = true;
+        self
+    }
+
     pub fn num_arguments(mut self, num_arguments: usize) -> FunctionFeatures {
         self.num_arguments = num_arguments;
         self
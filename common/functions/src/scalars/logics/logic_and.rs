@@ -33,6 +33,7 @@ impl LogicAndFunction {
                 .deterministic()
                 .negative_function("or")
                 .bool_function()
+                .commutative()
                 .num_arguments(2),
         )
     }
@@ -33,6 +33,7 @@ impl LogicOrFunction {
                 .deterministic()
                 .negative_function("and")
                 .bool_function()
+                .commutative()
                 .num_arguments(2),
         )
     }
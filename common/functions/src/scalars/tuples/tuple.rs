@@ -41,6 +41,11 @@ impl TupleFunction {
         }))
     }
 
+    // A null child is wired as an all-null field, keep the declared nullability otherwise.
+    fn is_nullable_field(data_type: &DataType, nullable: bool) -> bool {
+        nullable || data_type == &DataType::Null
+    }
+
     pub fn desc() -> FunctionDescription {
         FunctionDescription::creator(Box::new(Self::try_create_func)).features(
             FunctionFeatures::default()
@@ -60,7 +65,11 @@ impl Function for TupleFunction {
             .iter()
             .enumerate()
             .map(|(i, x)| {
-                DataField::new(format!("item_{}", i).as_str(), x.data_type().clone(), false)
+                DataField::new(
+                    format!("item_{}", i).as_str(),
+                    x.data_type().clone(),
+                    Self::is_nullable_field(x.data_type(), x.is_nullable()),
+                )
             })
             .collect::<Vec<_>>();
         let dt = DataType::Struct(fields);
@@ -75,7 +84,7 @@ impl Function for TupleFunction {
             let field = DataField::new(
                 format!("item_{}", i).as_str(),
                 xfield.data_type().clone(),
-                xfield.is_nullable(),
+                Self::is_nullable_field(xfield.data_type(), xfield.is_nullable()),
             );
             fields.push(field.to_arrow());
             arrays.push(x.column().to_array()?.get_array_ref());
@@ -15,7 +15,9 @@
 use std::collections::HashSet;
 use std::fmt;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::columns::DataColumnValidity;
 use common_datavalues::prelude::DataColumnsWithField;
 use common_datavalues::prelude::MutableArrayBuilder;
 use common_datavalues::prelude::MutableBooleanArrayBuilder;
@@ -33,20 +35,6 @@ use crate::scalars::Function;
 #[derive(Clone)]
 pub struct InFunction<const NEGATED: bool>;
 
-impl<const NEGATED: bool> InFunction<NEGATED> {
-    pub fn try_create(_display_name: &str) -> Result<Box<dyn Function>> {
-        Ok(Box::new(InFunction::<NEGATED> {}))
-    }
-
-    pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create)).features(
-            FunctionFeatures::default()
-                .bool_function()
-                .variadic_arguments(2, usize::MAX),
-        )
-    }
-}
-
 macro_rules! basic_contains {
     // bool no_null_iter returns bool not &bool, other types no_null_iter return &T
     ($INPUT_DT: expr, $INPUT_ARRAY: expr, $CHECK_ARRAY: expr, $NEGATED: expr, $BUILDER: expr, $CAST_TYPE: ident, bool) => {
@@ -122,22 +110,20 @@ macro_rules! float_contains {
     };
 }
 
-impl<const NEGATED: bool> Function for InFunction<NEGATED> {
-    fn name(&self) -> &str {
-        "InFunction"
+impl<const NEGATED: bool> InFunction<NEGATED> {
+    pub fn try_create(_display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(InFunction::<NEGATED> {}))
     }
 
-    fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
-        let input_dt = args[0].data_type();
-        if input_dt == &DataType::Null {
-            return Ok(DataTypeAndNullable::create(input_dt, false));
-        }
-
-        let dt = DataType::Boolean;
-        Ok(DataTypeAndNullable::create(&dt, false))
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create)).features(
+            FunctionFeatures::default()
+                .bool_function()
+                .variadic_arguments(2, usize::MAX),
+        )
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+    fn eval_contains(&self, columns: &DataColumnsWithField) -> Result<DataColumn> {
         let input_column = columns[0].column();
 
         let input_array = match input_column {
@@ -306,6 +292,48 @@ impl<const NEGATED: bool> Function for InFunction<NEGATED> {
     }
 }
 
+impl<const NEGATED: bool> Function for InFunction<NEGATED> {
+    fn name(&self) -> &str {
+        "InFunction"
+    }
+
+    fn return_type(&self, args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        let input_dt = args[0].data_type();
+        if input_dt == &DataType::Null {
+            return Ok(DataTypeAndNullable::create(input_dt, false));
+        }
+
+        let dt = DataType::Boolean;
+        let nullable = args.iter().any(|arg| arg.is_nullable());
+        Ok(DataTypeAndNullable::create(&dt, nullable))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let column = self.eval_contains(columns)?;
+
+        // A null input yields null, and so does a value not found in a list containing null,
+        // e.g. 2 IN (1, NULL) and 1 NOT IN (2, NULL) are both null.
+        let mut validities = vec![columns[0].column().get_validity()];
+        if columns[1..]
+            .iter()
+            .any(|c| c.column().get_validity().all_null())
+        {
+            let found: Bitmap = column
+                .to_array()?
+                .bool()?
+                .into_no_null_iter()
+                .map(|v| v != NEGATED)
+                .collect();
+            validities.push(DataColumnValidity::Array(Some(found), input_rows));
+        }
+        column.apply_validities(&validities)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
 impl<const NEGATED: bool> fmt::Display for InFunction<NEGATED> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if NEGATED {
@@ -47,8 +47,13 @@ where T: UUIDCreator + Clone + Sync + Send + 'static
     }
 
     pub fn desc() -> FunctionDescription {
-        FunctionDescription::creator(Box::new(Self::try_create))
-            .features(FunctionFeatures::default())
+        let mut features = FunctionFeatures::default();
+
+        if T::IS_DETERMINISTIC {
+            features = features.deterministic();
+        }
+
+        FunctionDescription::creator(Box::new(Self::try_create)).features(features)
     }
 }
 
@@ -59,6 +64,8 @@ impl<T> fmt::Display for UUIDCreatorFunction<T> {
 }
 
 pub trait UUIDCreator {
+    const IS_DETERMINISTIC: bool;
+
     fn create() -> Uuid;
 }
 
@@ -66,6 +73,8 @@ pub trait UUIDCreator {
 pub struct UUIDv4;
 
 impl UUIDCreator for UUIDv4 {
+    const IS_DETERMINISTIC: bool = false;
+
     fn create() -> Uuid {
         Uuid::new_v4()
     }
@@ -75,6 +84,8 @@ impl UUIDCreator for UUIDv4 {
 pub struct UUIDZero;
 
 impl UUIDCreator for UUIDZero {
+    const IS_DETERMINISTIC: bool = true;
+
     fn create() -> Uuid {
         Uuid::nil()
     }
@@ -1063,3 +1063,83 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_aggregate_sum_skip_nulls() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let args = vec![DataField::new("a", DataType::Int64, true)];
+
+    let run_sum = |array: Series| -> Result<DataValue> {
+        let rows = array.len();
+        let func = factory.get("sum", vec![], args.clone())?;
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &[array.clone()], rows)?;
+
+        // Every row goes to the same place by keys, nulls must be skipped too.
+        let addr_by_keys = arena.alloc_layout(func.state_layout());
+        func.init_state(addr_by_keys.into());
+        let places: Vec<StateAddr> = vec![addr_by_keys.into(); rows];
+        func.accumulate_keys(&places, 0, &[array], rows)?;
+
+        let mut builder = MutablePrimitiveArrayBuilder::<i64, true>::default();
+        func.merge_result(addr.into(), &mut builder)?;
+        func.merge_result(addr_by_keys.into(), &mut builder)?;
+        let result = builder.as_series();
+
+        assert_eq!(result.try_get(0)?, result.try_get(1)?);
+        result.try_get(0)
+    };
+
+    // Partially null, only the valid rows are folded.
+    let result = run_sum(Series::new([Some(1i64), None, Some(3), None]))?;
+    assert_eq!(result, DataValue::Int64(Some(4)));
+
+    // All null, no state change and the result is null.
+    let result = run_sum(Series::new([None::<i64>, None, None]))?;
+    assert!(result.is_null());
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_count_with_null_column() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+    let null_column = DataColumn::Constant(DataValue::Null, 4).to_array()?;
+
+    let run_count = |args: Vec<DataField>, arrays: Vec<Series>| -> Result<DataValue> {
+        let rows = 4;
+        let func = factory.get("count", vec![], args)?;
+
+        let addr = arena.alloc_layout(func.state_layout());
+        func.init_state(addr.into());
+        func.accumulate(addr.into(), &arrays, rows)?;
+
+        let addr_by_keys = arena.alloc_layout(func.state_layout());
+        func.init_state(addr_by_keys.into());
+        let places: Vec<StateAddr> = vec![addr_by_keys.into(); rows];
+        func.accumulate_keys(&places, 0, &arrays, rows)?;
+
+        let mut builder = MutablePrimitiveArrayBuilder::<u64, true>::default();
+        func.merge_result(addr.into(), &mut builder)?;
+        func.merge_result(addr_by_keys.into(), &mut builder)?;
+        let result = builder.as_series();
+
+        assert_eq!(result.try_get(0)?, result.try_get(1)?);
+        result.try_get(0)
+    };
+
+    // count(*) counts all the rows.
+    let result = run_count(vec![], vec![])?;
+    assert_eq!(result, DataValue::UInt64(Some(4)));
+
+    // count(null_col) counts none of the rows.
+    let args = vec![DataField::new("n", DataType::Null, true)];
+    let result = run_count(args, vec![null_column])?;
+    assert_eq!(result, DataValue::UInt64(Some(0)));
+
+    Ok(())
+}
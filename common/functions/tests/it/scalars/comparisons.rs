@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_datavalues::prelude::*;
+use common_datavalues::DataTypeAndNullable;
 use common_exception::Result;
 use common_functions::scalars::*;
 
@@ -146,3 +149,70 @@ fn test_not_like_comparison_function() -> Result<()> {
 
     test_scalar_functions(ComparisonNotLikeFunction::try_create_func("")?, &tests)
 }
+
+#[test]
+fn test_null_comparison_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        columns: Vec<DataColumnWithField>,
+    }
+
+    let null_column = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Null, 4),
+        DataField::new("null_col", DataType::Null, true),
+    );
+    let null_array_column = DataColumnWithField::new(
+        DataColumn::Array(DFNullArray::new(NullArray::new_null(ArrowType::Null, 4)).into_series()),
+        DataField::new("null_arr", DataType::Null, true),
+    );
+    let five_column = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Int64(Some(5)), 4),
+        DataField::new("five", DataType::Int64, false),
+    );
+
+    let tests = vec![
+        Test {
+            name: "null_col > 5",
+            func: ComparisonGtFunction::try_create_func("")?,
+            columns: vec![null_column.clone(), five_column.clone()],
+        },
+        Test {
+            name: "5 < null_col",
+            func: ComparisonLtFunction::try_create_func("")?,
+            columns: vec![five_column.clone(), null_column.clone()],
+        },
+        Test {
+            name: "null_col = null_col",
+            func: ComparisonEqFunction::try_create_func("")?,
+            columns: vec![null_column.clone(), null_column.clone()],
+        },
+        Test {
+            name: "null_arr > 5",
+            func: ComparisonGtFunction::try_create_func("")?,
+            columns: vec![null_array_column.clone(), five_column.clone()],
+        },
+        Test {
+            name: "5 = null_arr",
+            func: ComparisonEqFunction::try_create_func("")?,
+            columns: vec![five_column.clone(), null_array_column.clone()],
+        },
+    ];
+
+    for test in tests {
+        let arg_types = test
+            .columns
+            .iter()
+            .map(|c| DataTypeAndNullable::create(c.data_type(), c.field().is_nullable()))
+            .collect::<Vec<_>>();
+        let return_type = test.func.return_type(&arg_types)?;
+        assert_eq!(return_type.data_type(), &DataType::Boolean, "{}", test.name);
+        assert!(return_type.is_nullable(), "{}", test.name);
+
+        let result = test.func.eval(&test.columns, 4)?;
+        assert_eq!(result.len(), 4, "{}", test.name);
+        assert!(result.get_validity().all_null(), "{}", test.name);
+    }
+
+    Ok(())
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::NullArray;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
@@ -21,17 +23,194 @@ use crate::scalars::scalar_function_test::ScalarFunctionTest;
 
 #[test]
 fn test_if_function() -> Result<()> {
-    let tests = vec![ScalarFunctionTest {
-        name: "if-passed",
-        nullable: false,
-        columns: vec![
-            Series::new([true, false, false, true]).into(),
-            Series::new([1i32, 2, 3, 4]).into(),
-            Series::new([2.5_f64, 2.5_f64, 2.5_f64, 2.5_f64]).into(),
-        ],
-        expect: Series::new(vec![1f64, 2.5, 2.5, 4f64]).into(),
-        error: "",
-    }];
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "if-passed",
+            nullable: false,
+            columns: vec![
+                Series::new([true, false, false, true]).into(),
+                Series::new([1i32, 2, 3, 4]).into(),
+                Series::new([2.5_f64, 2.5_f64, 2.5_f64, 2.5_f64]).into(),
+            ],
+            expect: Series::new(vec![1f64, 2.5, 2.5, 4f64]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "if-null-condition-passed",
+            nullable: true,
+            columns: vec![
+                Series::new([Some(true), None, Some(false)]).into(),
+                Series::new([1i32, 2, 3]).into(),
+                Series::new([4i32, 5, 6]).into(),
+            ],
+            expect: Series::new([Some(1i32), None, Some(6)]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "if-null-branch-passed",
+            nullable: true,
+            columns: vec![
+                Series::new([true, false, true]).into(),
+                DataColumn::Constant(DataValue::Null, 3),
+                Series::new([4i32, 5, 6]).into(),
+            ],
+            expect: Series::new([None, Some(5i32), None]).into(),
+            error: "",
+        },
+    ];
 
     test_scalar_functions(IfFunction::try_create_func("")?, &tests)
 }
+
+#[test]
+fn test_if_function_null_result() -> Result<()> {
+    let func = IfFunction::try_create_func("")?;
+
+    // The null condition row must be null instead of picking the else branch.
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new([Some(true), None, Some(false)]).into(),
+            DataField::new("cond", DataType::Boolean, true),
+        ),
+        DataColumnWithField::new(
+            Series::new([1i32, 2, 3]).into(),
+            DataField::new("lhs", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            Series::new([4i32, 5, 6]).into(),
+            DataField::new("rhs", DataType::Int32, false),
+        ),
+    ];
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::Int32(Some(1)));
+    assert!(result.try_get(1)?.is_null());
+    assert_eq!(result.try_get(2)?, DataValue::Int32(Some(6)));
+
+    // One branch is all null, the picked rows of that branch are null.
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new([true, false, true]).into(),
+            DataField::new("cond", DataType::Boolean, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Null, 3),
+            DataField::new("lhs", DataType::Null, true),
+        ),
+        DataColumnWithField::new(
+            Series::new([4i32, 5, 6]).into(),
+            DataField::new("rhs", DataType::Int32, false),
+        ),
+    ];
+    let result = func.eval(&columns, 3)?.to_array()?;
+    assert!(result.try_get(0)?.is_null());
+    assert_eq!(result.try_get(1)?, DataValue::Int32(Some(5)));
+    assert!(result.try_get(2)?.is_null());
+
+    // An all null condition short-circuits to an all null column.
+    let columns = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Null, 2),
+            DataField::new("cond", DataType::Null, true),
+        ),
+        DataColumnWithField::new(
+            Series::new([1i32, 2]).into(),
+            DataField::new("lhs", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            Series::new([4i32, 5]).into(),
+            DataField::new("rhs", DataType::Int32, false),
+        ),
+    ];
+    let result = func.eval(&columns, 2)?;
+    assert_eq!(result.len(), 2);
+    assert!(result.get_validity().all_null());
+
+    // So does an array-backed Null condition.
+    let mut columns = columns;
+    columns[0] = DataColumnWithField::new(
+        DataColumn::Array(DFNullArray::new(NullArray::new_null(ArrowType::Null, 2)).into_series()),
+        DataField::new("cond", DataType::Null, true),
+    );
+    let result = func.eval(&columns, 2)?;
+    assert_eq!(result.len(), 2);
+    assert!(result.get_validity().all_null());
+
+    Ok(())
+}
+
+#[test]
+fn test_nullif_function() -> Result<()> {
+    let tests = vec![
+        ScalarFunctionTest {
+            name: "nullif-passed",
+            nullable: true,
+            columns: vec![
+                Series::new([1i32, 1, 2]).into(),
+                Series::new([1i32, 2, 3]).into(),
+            ],
+            expect: Series::new([None, Some(1i32), Some(2)]).into(),
+            error: "",
+        },
+        ScalarFunctionTest {
+            name: "nullif-null-input-passed",
+            nullable: true,
+            columns: vec![
+                Series::new([None, Some(1i32), Some(2)]).into(),
+                Series::new([Some(1i32), None, Some(2)]).into(),
+            ],
+            expect: Series::new([None, Some(1i32), None]).into(),
+            error: "",
+        },
+    ];
+
+    test_scalar_functions(NullIfFunction::try_create_func("")?, &tests)
+}
+
+#[test]
+fn test_nullif_function_constant() -> Result<()> {
+    let func = NullIfFunction::try_create_func("")?;
+
+    // NULLIF(1, 1) -> NULL
+    let columns = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int32(Some(1)), 1),
+            DataField::new("a", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int32(Some(1)), 1),
+            DataField::new("b", DataType::Int32, false),
+        ),
+    ];
+    let result = func.eval(&columns, 1)?.to_array()?;
+    assert!(result.try_get(0)?.is_null());
+
+    // NULLIF(1, 2) -> 1
+    let columns = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int32(Some(1)), 1),
+            DataField::new("a", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int32(Some(2)), 1),
+            DataField::new("b", DataType::Int32, false),
+        ),
+    ];
+    let result = func.eval(&columns, 1)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::Int32(Some(1)));
+
+    // NULLIF(1, NULL) -> 1
+    let columns = vec![
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Int32(Some(1)), 1),
+            DataField::new("a", DataType::Int32, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Null, 1),
+            DataField::new("b", DataType::Null, true),
+        ),
+    ];
+    let result = func.eval(&columns, 1)?.to_array()?;
+    assert_eq!(result.try_get(0)?, DataValue::Int32(Some(1)));
+
+    Ok(())
+}
@@ -106,6 +106,26 @@ fn test_cast_function() -> Result<()> {
                 error: "",
             },
         ),
+        (
+            CastFunction::create("cast".to_string(), DataType::String)?,
+            ScalarFunctionTest {
+                name: "cast-nullable-int64-to-string-passed",
+                nullable: true,
+                columns: vec![Series::new(vec![Some(4i64), None, Some(-2), None]).into()],
+                expect: Series::new(vec![Some("4"), None, Some("-2"), None]).into(),
+                error: "",
+            },
+        ),
+        (
+            CastFunction::create("cast".to_string(), DataType::String)?,
+            ScalarFunctionTest {
+                name: "cast-nullable-float64-to-string-passed",
+                nullable: true,
+                columns: vec![Series::new(vec![None, Some(1.5f64)]).into()],
+                expect: Series::new(vec![None, Some("1.5")]).into(),
+                error: "",
+            },
+        ),
     ];
 
     for (test_func, test) in tests {
@@ -118,6 +138,19 @@ fn test_cast_function() -> Result<()> {
 #[test]
 fn test_datetime_cast_function() -> Result<()> {
     let tests = vec![
+        (
+            CastFunction::create("cast".to_string(), DataType::String)?,
+            ScalarFunctionTestWithType {
+                name: "cast-nullable-date32-to-string-passed",
+                nullable: true,
+                columns: vec![DataColumnWithField::new(
+                    Series::new(vec![Some(18691i32), None]).into(),
+                    DataField::new("dummy_1", DataType::Date32, true),
+                )],
+                expect: Series::new(vec![Some("2021-03-05"), None]).into(),
+                error: "",
+            },
+        ),
         (
             CastFunction::create("cast".to_string(), DataType::String)?,
             ScalarFunctionTestWithType {
@@ -43,3 +43,38 @@ fn test_tuple_function() -> Result<()> {
 
     test_scalar_functions(TupleFunction::try_create_func("")?, &tests)
 }
+
+#[test]
+fn test_tuple_function_with_null_child() -> Result<()> {
+    let func = TupleFunction::try_create_func("")?;
+
+    let args = [
+        DataTypeAndNullable::create(&DataType::UInt8, false),
+        DataTypeAndNullable::create(&DataType::Null, true),
+    ];
+    let return_type = func.return_type(&args)?;
+    let expect_type = DataType::Struct(vec![
+        DataField::new("item_0", DataType::UInt8, false),
+        DataField::new("item_1", DataType::Null, true),
+    ]);
+    assert_eq!(return_type.data_type(), &expect_type);
+
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new([1_u8, 2]).into(),
+            DataField::new("a", DataType::UInt8, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::Null, 2),
+            DataField::new("b", DataType::Null, true),
+        ),
+    ];
+    let result = func.eval(&columns, 2)?;
+    assert_eq!(result.data_type(), expect_type);
+    assert_eq!(
+        result.try_get(1)?,
+        DataValue::Struct(vec![DataValue::UInt8(Some(2)), DataValue::Null])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_in_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        negated: bool,
+        list: Vec<DataValue>,
+        expect: Vec<Option<bool>>,
+    }
+
+    // The probe column is [1, 2, NULL].
+    let tests = vec![
+        Test {
+            name: "in",
+            negated: false,
+            list: vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(3))],
+            expect: vec![Some(true), Some(false), None],
+        },
+        Test {
+            name: "not in",
+            negated: true,
+            list: vec![DataValue::Int64(Some(1)), DataValue::Int64(Some(3))],
+            expect: vec![Some(false), Some(true), None],
+        },
+        Test {
+            name: "in with null in list",
+            negated: false,
+            list: vec![DataValue::Int64(Some(1)), DataValue::Null],
+            expect: vec![Some(true), None, None],
+        },
+        Test {
+            name: "not in with null in list",
+            negated: true,
+            list: vec![DataValue::Int64(Some(1)), DataValue::Null],
+            expect: vec![Some(false), None, None],
+        },
+    ];
+
+    for test in tests {
+        let func = if test.negated {
+            InFunction::<true>::try_create("not in")?
+        } else {
+            InFunction::<false>::try_create("in")?
+        };
+
+        let mut columns = vec![DataColumnWithField::new(
+            Series::new([Some(1i64), Some(2), None]).into(),
+            DataField::new("x", DataType::Int64, true),
+        )];
+        for (i, value) in test.list.iter().enumerate() {
+            columns.push(DataColumnWithField::new(
+                DataColumn::Constant(value.clone(), 3),
+                DataField::new(&format!("v{}", i), value.data_type(), value.is_null()),
+            ));
+        }
+
+        let args = columns
+            .iter()
+            .map(|c| DataTypeAndNullable::create(c.data_type(), c.field().is_nullable()))
+            .collect::<Vec<_>>();
+        assert!(func.return_type(&args)?.is_nullable(), "{}", test.name);
+
+        let result = func.eval(&columns, 3)?.to_array()?;
+        assert_eq!(
+            result.bool()?.collect_values(),
+            test.expect,
+            "{}",
+            test.name
+        );
+    }
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod database;
+mod in_basic;
 mod to_type_name;
 mod udf_example;
 mod version;
@@ -43,3 +43,18 @@ fn test_uuid_creator_functions() -> Result<()> {
 
     test_scalar_functions(UUIDZeroFunction::try_create("")?, &tests)
 }
+
+#[test]
+fn test_uuid_v4_is_not_deterministic() -> Result<()> {
+    let factory = FunctionFactory::instance();
+    assert!(factory.get_features("zeroUUID")?.is_deterministic);
+    assert!(!factory.get_features("generateUUIDv4")?.is_deterministic);
+
+    let func = UUIDv4Function::try_create("generateUUIDv4")?;
+
+    let first = func.eval(&[], 1)?.try_get(0)?;
+    let second = func.eval(&[], 1)?.try_get(0)?;
+    assert_ne!(first, second);
+
+    Ok(())
+}
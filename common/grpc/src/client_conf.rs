@@ -13,7 +13,7 @@
 //  limitations under the License.
 //
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct RpcClientTlsConfig {
     pub rpc_tls_server_root_ca_cert: String,
     pub domain_name: String,
@@ -25,7 +25,7 @@ impl RpcClientTlsConfig {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct RpcClientConf {
     pub address: String,
     pub username: String,
@@ -18,6 +18,9 @@ use bincode::Options;
 use bytes::BufMut;
 use common_exception::Result;
 
+/// The text of null rendered by default, shared by the text serializers of the values.
+pub const DEFAULT_NULL_DISPLAY: &str = "NULL";
+
 pub fn convert_byte_size(num: f64) -> String {
     let negative = if num.is_sign_positive() { "" } else { "-" };
     let num = num.abs();
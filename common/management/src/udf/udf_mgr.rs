@@ -100,7 +100,7 @@ impl UdfMgrApi for UdfMgr {
         let res = upsert_info.await?;
         match res.result {
             Some(SeqV { seq: s, .. }) => Ok(s),
-            None => Err(ErrorCode::UnknownUDF(format!(
+            None => Err(ErrorCode::UnknownUdf(format!(
                 "unknown UDF, or seq not match {}",
                 info.name.clone()
             ))),
@@ -113,7 +113,7 @@ impl UdfMgrApi for UdfMgr {
         let get_kv = async move { kv_api.get_kv(&key).await };
         let res = get_kv.await?;
         let seq_value =
-            res.ok_or_else(|| ErrorCode::UnknownUDF(format!("Unknown UDF {}", udf_name)))?;
+            res.ok_or_else(|| ErrorCode::UnknownUdf(format!("Unknown UDF {}", udf_name)))?;
 
         match MatchSeq::from(seq).match_seq(&seq_value) {
             Ok(_) => Ok(seq_value.into_seqv()?),
@@ -149,7 +149,7 @@ impl UdfMgrApi for UdfMgr {
         if res.prev.is_some() && res.result.is_none() {
             Ok(())
         } else {
-            Err(ErrorCode::UnknownUDF(format!("Unknown UDF {}", udf_name)))
+            Err(ErrorCode::UnknownUdf(format!("Unknown UDF {}", udf_name)))
         }
     }
 }
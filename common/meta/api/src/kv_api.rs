@@ -16,11 +16,22 @@
 use std::ops::Deref;
 
 use async_trait::async_trait;
+use common_exception::ErrorCode;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
 use common_meta_types::PrefixListReply;
+use common_meta_types::SeqV;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+/// How many times the default `cas_kv` compares again when the key is changed in between.
+pub const CAS_KV_MAX_RETRIES: usize = 32;
 
 #[async_trait]
 pub trait KVApiBuilder<T>
@@ -44,6 +55,60 @@ pub trait KVApi: Send + Sync {
     async fn mget_kv(&self, key: &[String]) -> common_exception::Result<MGetKVActionReply>;
 
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply>;
+
+    /// List the kv of a prefix as a stream.
+    ///
+    /// By default the items are listed at once by `prefix_list_kv`.
+    async fn list_kv_stream(
+        &self,
+        prefix: &str,
+    ) -> common_exception::Result<
+        BoxStream<'static, common_exception::Result<(String, SeqV<Vec<u8>>)>>,
+    > {
+        let items = self.prefix_list_kv(prefix).await?;
+        Ok(futures::stream::iter(items.into_iter().map(Ok)).boxed())
+    }
+
+    /// Compare-and-swap the value of a key.
+    ///
+    /// By default the swap is an upsert matching the seq of the compared value, keeping its meta.
+    /// It is read again and compared if the key is changed in between, at most
+    /// `CAS_KV_MAX_RETRIES` times.
+    /// A store applying the compare and the swap at once should override it.
+    async fn cas_kv(&self, act: CasKVAction) -> common_exception::Result<CasKVReply> {
+        for _ in 0..CAS_KV_MAX_RETRIES {
+            let prev = self.get_kv(&act.key).await?;
+            if prev.as_ref().map(|v| &v.data) != act.expected.as_ref() {
+                return Ok(CasKVReply {
+                    success: false,
+                    prev,
+                });
+            }
+
+            let prev_seq = prev.as_ref().map(|v| v.seq);
+            let prev_meta = prev.as_ref().and_then(|v| v.meta.clone());
+            let value = match &act.value {
+                Some(value) => Operation::Update(value.clone()),
+                None => Operation::Delete,
+            };
+            let seq = MatchSeq::Exact(prev_seq.unwrap_or(0));
+            let change = self
+                .upsert_kv(UpsertKVAction::new(&act.key, seq, value, prev_meta))
+                .await?;
+
+            if change.prev.as_ref().map(|v| v.seq) == prev_seq {
+                return Ok(CasKVReply {
+                    success: true,
+                    prev,
+                });
+            }
+        }
+
+        Err(ErrorCode::OCCRetryFailure(format!(
+            "cas_kv of key {} exceeds the max retries {}",
+            act.key, CAS_KV_MAX_RETRIES
+        )))
+    }
 }
 
 #[async_trait]
@@ -66,4 +131,17 @@ impl<U: KVApi, T: Deref<Target = U> + Send + Sync> KVApi for T {
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply> {
         self.deref().prefix_list_kv(prefix).await
     }
+
+    async fn list_kv_stream(
+        &self,
+        prefix: &str,
+    ) -> common_exception::Result<
+        BoxStream<'static, common_exception::Result<(String, SeqV<Vec<u8>>)>>,
+    > {
+        self.deref().list_kv_stream(prefix).await
+    }
+
+    async fn cas_kv(&self, act: CasKVAction) -> common_exception::Result<CasKVReply> {
+        self.deref().cas_kv(act).await
+    }
 }
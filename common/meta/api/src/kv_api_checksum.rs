@@ -0,0 +1,193 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use async_trait::async_trait;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GetKVActionReply;
+use common_meta_types::MGetKVActionReply;
+use common_meta_types::Operation;
+use common_meta_types::PrefixListReply;
+use common_meta_types::SeqV;
+use common_meta_types::UpsertKVAction;
+use common_meta_types::UpsertKVActionReply;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use futures::TryStreamExt;
+
+use crate::KVApi;
+
+/// Marks a value carrying a checksum header, followed by the header version.
+const CHECKSUM_MAGIC: [u8; 3] = [0xdb, b'c', b'k'];
+const CHECKSUM_VERSION: u8 = 1;
+const CHECKSUM_SIZE: usize = 4;
+const CHECKSUM_HEADER_SIZE: usize = CHECKSUM_MAGIC.len() + 1 + CHECKSUM_SIZE;
+
+/// A KVApi storing a crc32 checksum alongside each value, verified on read.
+///
+/// The checksum is prepended to the value as a header: the magic, the version and the 4
+/// little-endian bytes of the crc32, it is stripped before the value is returned.
+/// A value without the header, written before checksums are enabled, is returned unverified.
+/// With `write_checksum` off, values are written as is but the verified headers are still stripped
+/// on read, so that checksums can be turned off again, or differ between the nodes of a cluster.
+/// A header failing to verify is `DataCorruption` with `write_checksum` on, while it is off,
+/// the value is taken as a legacy one that happens to start with the magic and returned as is.
+pub struct ChecksumKVApi<T: KVApi> {
+    inner: T,
+    write_checksum: bool,
+}
+
+impl<T: KVApi> ChecksumKVApi<T> {
+    pub fn new(inner: T) -> Self {
+        Self::create(inner, true)
+    }
+
+    pub fn create(inner: T, write_checksum: bool) -> Self {
+        ChecksumKVApi {
+            inner,
+            write_checksum,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+}
+
+fn append_checksum(value: Vec<u8>) -> Vec<u8> {
+    let checksum = crc32fast::hash(&value);
+    let mut res = Vec::with_capacity(CHECKSUM_HEADER_SIZE + value.len());
+    res.extend_from_slice(&CHECKSUM_MAGIC);
+    res.push(CHECKSUM_VERSION);
+    res.extend_from_slice(&checksum.to_le_bytes());
+    res.extend_from_slice(&value);
+    res
+}
+
+// Strip the checksum header of the value if it verifies. A header failing to verify is an error
+// if `strict`, otherwise the value is a legacy one starting with the magic by chance.
+fn verify_checksum(key: &str, mut value: Vec<u8>, strict: bool) -> Result<Vec<u8>> {
+    if !value.starts_with(&CHECKSUM_MAGIC) {
+        // A legacy value, written without checksum.
+        return Ok(value);
+    }
+
+    match check_header(key, &value) {
+        Ok(()) => {
+            value.drain(..CHECKSUM_HEADER_SIZE);
+            Ok(value)
+        }
+        Err(e) if strict => Err(e),
+        Err(_) => Ok(value),
+    }
+}
+
+fn check_header(key: &str, value: &[u8]) -> Result<()> {
+    if value.len() < CHECKSUM_HEADER_SIZE {
+        return Err(ErrorCode::DataCorruption(format!(
+            "Value of key {} is too short to have a checksum header",
+            key
+        )));
+    }
+
+    let version = value[CHECKSUM_MAGIC.len()];
+    if version != CHECKSUM_VERSION {
+        return Err(ErrorCode::DataCorruption(format!(
+            "Unknown checksum version {} of key {}",
+            version, key
+        )));
+    }
+
+    let mut checksum = [0u8; CHECKSUM_SIZE];
+    checksum.copy_from_slice(&value[CHECKSUM_MAGIC.len() + 1..CHECKSUM_HEADER_SIZE]);
+    let expected = u32::from_le_bytes(checksum);
+    let actual = crc32fast::hash(&value[CHECKSUM_HEADER_SIZE..]);
+    if expected != actual {
+        return Err(ErrorCode::DataCorruption(format!(
+            "Checksum mismatch of key {}, expected: {:#010x}, actual: {:#010x}",
+            key, expected, actual
+        )));
+    }
+    Ok(())
+}
+
+fn verify_seq_v(key: &str, seq_v: SeqV<Vec<u8>>, strict: bool) -> Result<SeqV<Vec<u8>>> {
+    Ok(SeqV {
+        seq: seq_v.seq,
+        meta: seq_v.meta,
+        data: verify_checksum(key, seq_v.data, strict)?,
+    })
+}
+
+fn verify_opt_seq_v(
+    key: &str,
+    seq_v: Option<SeqV<Vec<u8>>>,
+    strict: bool,
+) -> Result<Option<SeqV<Vec<u8>>>> {
+    seq_v
+        .map(|seq_v| verify_seq_v(key, seq_v, strict))
+        .transpose()
+}
+
+#[async_trait]
+impl<T: KVApi> KVApi for ChecksumKVApi<T> {
+    async fn upsert_kv(&self, act: UpsertKVAction) -> Result<UpsertKVActionReply> {
+        let key = act.key.clone();
+        let value = match act.value {
+            Operation::Update(value) if self.write_checksum => {
+                Operation::Update(append_checksum(value))
+            }
+            other => other,
+        };
+
+        let mut reply = self
+            .inner
+            .upsert_kv(UpsertKVAction { value, ..act })
+            .await?;
+        reply.prev = verify_opt_seq_v(&key, reply.prev, self.write_checksum)?;
+        reply.result = verify_opt_seq_v(&key, reply.result, self.write_checksum)?;
+        Ok(reply)
+    }
+
+    async fn get_kv(&self, key: &str) -> Result<GetKVActionReply> {
+        let reply = self.inner.get_kv(key).await?;
+        verify_opt_seq_v(key, reply, self.write_checksum)
+    }
+
+    async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVActionReply> {
+        let reply = self.inner.mget_kv(keys).await?;
+        keys.iter()
+            .zip(reply.into_iter())
+            .map(|(key, seq_v)| verify_opt_seq_v(key, seq_v, self.write_checksum))
+            .collect()
+    }
+
+    async fn prefix_list_kv(&self, prefix: &str) -> Result<PrefixListReply> {
+        self.list_kv_stream(prefix).await?.try_collect().await
+    }
+
+    async fn list_kv_stream(
+        &self,
+        prefix: &str,
+    ) -> Result<BoxStream<'static, Result<(String, SeqV<Vec<u8>>)>>> {
+        let strict = self.write_checksum;
+        let items = self.inner.list_kv_stream(prefix).await?;
+        let items = items.and_then(move |(key, seq_v)| async move {
+            let seq_v = verify_seq_v(&key, seq_v, strict)?;
+            Ok((key, seq_v))
+        });
+        Ok(items.boxed())
+    }
+}
@@ -16,6 +16,7 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use common_base::tokio;
+use common_meta_types::CasKVAction;
 use common_meta_types::KVMeta;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
@@ -42,6 +43,7 @@ impl KVApiTestSuite {
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
+        self.kv_cas(&builder.build().await).await?;
 
         // Run cross node test on every 2 adjacent nodes
 
@@ -123,6 +125,59 @@ impl KVApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "info", skip(self, kv))]
+    pub async fn kv_cas<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        {
+            // swap an absent key
+            let res = kv
+                .cas_kv(CasKVAction::new("cas", None, Some(b"v1".to_vec())))
+                .await?;
+            assert!(res.success);
+            assert_eq!(None, res.prev);
+        }
+
+        {
+            // the current value mismatches
+            let res = kv
+                .cas_kv(CasKVAction::new("cas", None, Some(b"v2".to_vec())))
+                .await?;
+            assert!(!res.success);
+            assert_eq!(Some(b"v1".to_vec()), res.prev.map(|v| v.data));
+
+            let res = kv.get_kv("cas").await?;
+            assert_eq!(Some(b"v1".to_vec()), res.map(|v| v.data), "nothing changed");
+        }
+
+        {
+            // the current value matches
+            let res = kv
+                .cas_kv(CasKVAction::new(
+                    "cas",
+                    Some(b"v1".to_vec()),
+                    Some(b"v2".to_vec()),
+                ))
+                .await?;
+            assert!(res.success);
+            assert_eq!(Some(b"v1".to_vec()), res.prev.map(|v| v.data));
+
+            let res = kv.get_kv("cas").await?;
+            assert_eq!(Some(b"v2".to_vec()), res.map(|v| v.data));
+        }
+
+        {
+            // delete
+            let res = kv
+                .cas_kv(CasKVAction::new("cas", Some(b"v2".to_vec()), None))
+                .await?;
+            assert!(res.success);
+
+            let res = kv.get_kv("cas").await?;
+            assert_eq!(None, res);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, kv))]
     pub async fn kv_delete<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         let test_key = "test_key";
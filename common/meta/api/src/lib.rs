@@ -16,12 +16,15 @@
 extern crate common_meta_types;
 
 mod kv_api;
+mod kv_api_checksum;
 mod kv_api_test_suite;
 mod meta_api;
 mod meta_api_test_suite;
 
 pub use kv_api::KVApi;
 pub use kv_api::KVApiBuilder;
+pub use kv_api::CAS_KV_MAX_RETRIES;
+pub use kv_api_checksum::ChecksumKVApi;
 pub use kv_api_test_suite::KVApiTestSuite;
 pub use meta_api::MetaApi;
 pub use meta_api_test_suite::MetaApiTestSuite;
@@ -16,6 +16,8 @@ use async_trait::async_trait;
 use common_exception::Result;
 use common_meta_api::KVApi;
 pub use common_meta_sled_store::init_temp_sled_db;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::PrefixListReply;
@@ -48,4 +50,9 @@ impl KVApi for MetaEmbedded {
         let sm = self.inner.lock().await;
         sm.prefix_list_kv(prefix).await
     }
+
+    async fn cas_kv(&self, act: CasKVAction) -> Result<CasKVReply> {
+        let sm = self.inner.lock().await;
+        sm.cas_kv(act).await
+    }
 }
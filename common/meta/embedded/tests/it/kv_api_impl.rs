@@ -13,8 +13,15 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
+use common_meta_api::ChecksumKVApi;
+use common_meta_api::KVApi;
 use common_meta_api::KVApiTestSuite;
 use common_meta_embedded::MetaEmbedded;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+use futures::TryStreamExt;
 
 #[tokio::test]
 async fn test_kv_write_read() -> anyhow::Result<()> {
@@ -57,3 +64,148 @@ async fn test_kv_mget() -> anyhow::Result<()> {
     let kv = MetaEmbedded::new_temp().await?;
     KVApiTestSuite {}.kv_mget(&kv).await
 }
+
+#[tokio::test]
+async fn test_kv_cas() -> anyhow::Result<()> {
+    let kv = MetaEmbedded::new_temp().await?;
+    KVApiTestSuite {}.kv_cas(&kv).await
+}
+
+#[tokio::test]
+async fn test_kv_checksum() -> anyhow::Result<()> {
+    // The checksum is transparent to the kv api users.
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+    KVApiTestSuite {}.kv_write_read(&kv).await?;
+
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+    KVApiTestSuite {}.kv_update(&kv).await?;
+
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+    KVApiTestSuite {}.kv_list(&kv).await?;
+
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+    KVApiTestSuite {}.kv_mget(&kv).await?;
+
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+    KVApiTestSuite {}.kv_cas(&kv).await
+}
+
+#[tokio::test]
+async fn test_kv_checksum_corruption() -> anyhow::Result<()> {
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+
+    kv.upsert_kv(UpsertKVAction::new(
+        "k1",
+        MatchSeq::Any,
+        Operation::Update(b"v1".to_vec()),
+        None,
+    ))
+    .await?;
+    let got = kv.get_kv("k1").await?;
+    assert_eq!(b"v1".to_vec(), got.unwrap().data);
+
+    // Overwrite the checksummed data, keeping the header.
+    let mut stored = kv.inner().get_kv("k1").await?.unwrap().data;
+    let header_size = stored.len() - b"v1".len();
+    stored.truncate(header_size);
+    stored.extend_from_slice(b"v2");
+    kv.inner()
+        .upsert_kv(UpsertKVAction::new(
+            "k1",
+            MatchSeq::Any,
+            Operation::Update(stored),
+            None,
+        ))
+        .await?;
+
+    let res = kv.get_kv("k1").await;
+    assert_eq!(ErrorCode::data_corruption_code(), res.unwrap_err().code());
+
+    let res = kv.mget_kv(&["k1".to_string()]).await;
+    assert_eq!(ErrorCode::data_corruption_code(), res.unwrap_err().code());
+
+    let res = kv.prefix_list_kv("k").await;
+    assert_eq!(ErrorCode::data_corruption_code(), res.unwrap_err().code());
+
+    let res: common_exception::Result<Vec<_>> = kv.list_kv_stream("k").await?.try_collect().await;
+    assert_eq!(ErrorCode::data_corruption_code(), res.unwrap_err().code());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_kv_checksum_compatibility() -> anyhow::Result<()> {
+    let kv = ChecksumKVApi::new(MetaEmbedded::new_temp().await?);
+
+    // A value written before checksums are enabled is read unverified.
+    kv.inner()
+        .upsert_kv(UpsertKVAction::new(
+            "legacy",
+            MatchSeq::Any,
+            Operation::Update(b"v1".to_vec()),
+            None,
+        ))
+        .await?;
+    let got = kv.get_kv("legacy").await?;
+    assert_eq!(b"v1".to_vec(), got.unwrap().data);
+
+    kv.upsert_kv(UpsertKVAction::new(
+        "checked",
+        MatchSeq::Any,
+        Operation::Update(b"v2".to_vec()),
+        None,
+    ))
+    .await?;
+
+    // With checksum writes turned off, the checksummed values are still read without the header.
+    let kv = ChecksumKVApi::create(kv.inner().clone(), false);
+    kv.upsert_kv(UpsertKVAction::new(
+        "unchecked",
+        MatchSeq::Any,
+        Operation::Update(b"v3".to_vec()),
+        None,
+    ))
+    .await?;
+
+    let got = kv.get_kv("unchecked").await?;
+    assert_eq!(b"v3".to_vec(), got.unwrap().data);
+    let got = kv.inner().get_kv("unchecked").await?;
+    assert_eq!(b"v3".to_vec(), got.unwrap().data);
+
+    let got = kv.get_kv("checked").await?;
+    assert_eq!(b"v2".to_vec(), got.unwrap().data);
+
+    let got: Vec<_> = kv.list_kv_stream("").await?.try_collect().await?;
+    let got = got
+        .into_iter()
+        .map(|(key, seq_v)| (key, seq_v.data))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("checked".to_string(), b"v2".to_vec()),
+            ("legacy".to_string(), b"v1".to_vec()),
+            ("unchecked".to_string(), b"v3".to_vec()),
+        ],
+        got
+    );
+
+    // With checksum writes off, a legacy value starting with the magic but failing to verify
+    // is returned as is, with them on, it is taken as corrupted.
+    let legacy = vec![0xdb, b'c', b'k', 1, 0, 0, 0, 0, b'v'];
+    kv.inner()
+        .upsert_kv(UpsertKVAction::new(
+            "legacy_magic",
+            MatchSeq::Any,
+            Operation::Update(legacy.clone()),
+            None,
+        ))
+        .await?;
+    let got = kv.get_kv("legacy_magic").await?;
+    assert_eq!(legacy, got.unwrap().data);
+
+    let kv = ChecksumKVApi::create(kv.inner().clone(), true);
+    let res = kv.get_kv("legacy_magic").await;
+    assert_eq!(ErrorCode::data_corruption_code(), res.unwrap_err().code());
+
+    Ok(())
+}
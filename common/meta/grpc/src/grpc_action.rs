@@ -18,6 +18,8 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
@@ -44,6 +46,12 @@ use tonic::Request;
 
 pub trait RequestFor {
     type Reply;
+
+    /// Whether sending the request more than once has the same effect as sending it once.
+    /// Only idempotent requests are retried on a transient failure such as a timeout.
+    fn idempotent() -> bool {
+        false
+    }
 }
 
 // Action wrapper for do_action.
@@ -57,6 +65,7 @@ pub enum MetaGrpcWriteReq {
     CommitTable(UpsertTableOptionReq),
 
     UpsertKV(UpsertKVAction),
+    CasKV(CasKVAction),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, derive_more::From)]
@@ -146,6 +155,10 @@ pub struct GetKVAction {
 // this can be simplified by using macro (see code below)
 impl RequestFor for GetKVAction {
     type Reply = GetKVActionReply;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 // - MGetKV
@@ -159,6 +172,10 @@ pub struct MGetKVAction {
 // here we use a macro to simplify the declarations
 impl RequestFor for MGetKVAction {
     type Reply = MGetKVActionReply;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 // - prefix list
@@ -166,12 +183,20 @@ impl RequestFor for MGetKVAction {
 pub struct PrefixListReq(pub String);
 impl RequestFor for PrefixListReq {
     type Reply = PrefixListReply;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 impl RequestFor for UpsertKVAction {
     type Reply = UpsertKVActionReply;
 }
 
+impl RequestFor for CasKVAction {
+    type Reply = CasKVReply;
+}
+
 // == database actions ==
 
 impl RequestFor for CreateDatabaseReq {
@@ -180,6 +205,10 @@ impl RequestFor for CreateDatabaseReq {
 
 impl RequestFor for GetDatabaseReq {
     type Reply = Arc<DatabaseInfo>;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 impl RequestFor for DropDatabaseReq {
@@ -196,6 +225,10 @@ impl RequestFor for DropTableReq {
 
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -204,6 +237,10 @@ pub struct GetTableExtReq {
 }
 impl RequestFor for GetTableExtReq {
     type Reply = TableInfo;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 impl RequestFor for UpsertTableOptionReq {
@@ -212,8 +249,16 @@ impl RequestFor for UpsertTableOptionReq {
 
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
 
 impl RequestFor for ListDatabaseReq {
     type Reply = Vec<Arc<DatabaseInfo>>;
+
+    fn idempotent() -> bool {
+        true
+    }
 }
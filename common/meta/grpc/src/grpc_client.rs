@@ -13,11 +13,16 @@
 // limitations under the License.
 
 use std::fmt::Debug;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
+use common_base::tokio;
+use common_base::tokio::sync::OwnedSemaphorePermit;
 use common_base::tokio::sync::RwLock;
+use common_base::tokio::sync::Semaphore;
 use common_containers::ItemManager;
 use common_containers::Pool;
 use common_exception::ErrorCode;
@@ -27,10 +32,17 @@ use common_grpc::ConnectionFactory;
 use common_grpc::RpcClientTlsConfig;
 use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
 use common_meta_types::protobuf::HandshakeRequest;
+use common_meta_types::protobuf::PingRequest;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::PrefixListReply;
+use common_meta_types::SeqV;
 use common_tracing::tracing;
+use futures::stream::BoxStream;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
+use metrics::decrement_gauge;
+use metrics::increment_gauge;
 use prost::Message;
 use serde::de::DeserializeOwned;
 use tonic::async_trait;
@@ -45,6 +57,7 @@ use tonic::Status;
 
 use crate::grpc_action::MetaGrpcReadReq;
 use crate::grpc_action::MetaGrpcWriteReq;
+use crate::grpc_action::PrefixListReq;
 use crate::grpc_action::RequestFor;
 use crate::MetaGrpcClientConf;
 
@@ -80,23 +93,51 @@ pub struct MetaGrpcClient {
     username: String,
     password: String,
     token: Arc<RwLock<Option<Vec<u8>>>>,
+    request_timeout: Option<Duration>,
+    max_retries: u32,
+    /// How many times a request is sent at most when the token is renewed before retrying.
+    max_token_renewals: u32,
+    /// Bounds the in-flight requests, None means unbounded.
+    inflight: Option<Arc<Semaphore>>,
 }
 
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
 
+/// The default of how many times a request is sent at most,
+/// a rejected token, or an internal error of an idempotent request, resets the token before the next attempt.
+pub const META_RPC_MAX_TOKEN_RENEWALS: u32 = 2;
+
+/// The delay before the first retry of a transient failure, doubled for each following retry.
+const META_RPC_RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
+/// The number of requests waiting for an in-flight slot.
+pub static METRIC_META_GRPC_CLIENT_QUEUED: &str = "meta.grpc_client.queued_requests";
+
 impl MetaGrpcClient {
     pub async fn try_new(conf: &MetaGrpcClientConf) -> Result<MetaGrpcClient> {
         let mgr = MetaChannelManager {
             timeout: Some(Duration::from_secs(conf.client_timeout_in_second)),
             conf: conf.meta_service_config.tls_conf.clone(),
         };
+        let mut conn_pool = Pool::new(mgr, Duration::from_millis(50));
+        if conf.pool_idle_timeout_in_second > 0 {
+            conn_pool =
+                conn_pool.with_idle_timeout(Duration::from_secs(conf.pool_idle_timeout_in_second));
+        }
+
         Ok(Self {
-            conn_pool: Pool::new(mgr, Duration::from_millis(50)),
+            conn_pool,
             addr: conf.meta_service_config.address.to_string(),
             username: conf.meta_service_config.username.to_string(),
             password: conf.meta_service_config.password.to_string(),
             token: Arc::new(RwLock::new(None)),
-        })
+            request_timeout: conf.request_timeout,
+            max_retries: conf.max_retries,
+            max_token_renewals: META_RPC_MAX_TOKEN_RENEWALS,
+            inflight: None,
+        }
+        .with_max_token_renewals(conf.max_token_renewals)
+        .with_max_concurrent_requests(conf.max_concurrent_requests))
     }
 
     #[tracing::instrument(level = "debug", skip(password))]
@@ -115,9 +156,47 @@ impl MetaGrpcClient {
             username: username.to_string(),
             password: password.to_string(),
             token: Arc::new(RwLock::new(None)),
+            request_timeout: None,
+            max_retries: 0,
+            max_token_renewals: META_RPC_MAX_TOKEN_RENEWALS,
+            inflight: None,
         })
     }
 
+    /// Fail a request with `ErrorCode::Timeout` if it is not replied in time,
+    /// including the time to connect and to handshake.
+    pub fn with_request_timeout(mut self, request_timeout: Option<Duration>) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Retry an idempotent request at most `max_retries` times if it fails transiently,
+    /// e.g. the meta server is unavailable or the request timed out.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Send a request at most `max_token_renewals` times when the server rejects the token,
+    /// or fails internally on an idempotent request, 0 means `META_RPC_MAX_TOKEN_RENEWALS`.
+    pub fn with_max_token_renewals(mut self, max_token_renewals: u32) -> Self {
+        self.max_token_renewals = match max_token_renewals {
+            0 => META_RPC_MAX_TOKEN_RENEWALS,
+            n => n,
+        };
+        self
+    }
+
+    /// Let at most `max_concurrent_requests` requests be in flight,
+    /// the excess ones wait for a slot, 0 means unbounded.
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.inflight = match max_concurrent_requests {
+            0 => None,
+            n => Some(Arc::new(Semaphore::new(n))),
+        };
+        self
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn make_client(
         &self,
@@ -171,38 +250,58 @@ impl MetaGrpcClient {
         Ok(token)
     }
 
+    /// Round-trip a ping to the meta server and return the latency.
+    /// It needs no handshake and touches no keys, an unreachable server fails with `CannotConnectNode`.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn ping(&self) -> Result<Duration> {
+        let cannot_connect = |e: &dyn std::fmt::Display| {
+            ErrorCode::CannotConnectNode(format!("Ping meta server {} failed: {}", self.addr, e))
+        };
+
+        let channel = self
+            .conn_pool
+            .get(&self.addr)
+            .await
+            .map_err(|e| cannot_connect(&e))?;
+        let mut client = MetaServiceClient::new(channel);
+
+        let started = Instant::now();
+        match client.ping(PingRequest {}).await {
+            Ok(_) => Ok(started.elapsed()),
+            Err(s) if s.code() == Code::Unavailable => Err(cannot_connect(&s)),
+            Err(s) => Err(s.into()),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, v))]
     pub(crate) async fn do_write<T, R>(&self, v: T) -> Result<R>
     where
         T: RequestFor<Reply = R> + Into<MetaGrpcWriteReq>,
         R: DeserializeOwned,
     {
+        let idempotent = T::idempotent();
         let act: MetaGrpcWriteReq = v.into();
-        let req: Request<RaftRequest> = (&act.clone()).try_into()?;
-        let req = common_tracing::inject_span_to_tonic_request(req);
+        let _permit = self.acquire_inflight().await?;
 
-        let mut client = self.make_client().await?;
-        let result = client.write_msg(req).await;
-        let result: std::result::Result<RaftReply, Status> = match result {
-            Ok(r) => Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    {
-                        let mut token = self.token.write().await;
-                        *token = None;
-                    }
+        let started = Instant::now();
+        let mut attempts = 0;
+        let result = loop {
+            attempts += 1;
+            let req: Request<RaftRequest> = (&act).try_into()?;
+            let req = common_tracing::inject_span_to_tonic_request(req);
+
+            let reply = self
+                .timed(async {
                     let mut client = self.make_client().await?;
-                    let req: Request<RaftRequest> = (&act).try_into()?;
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    Ok(client.write_msg(req).await?.into_inner())
-                } else {
-                    Err(s)
-                }
+                    Ok(client.write_msg(req).await)
+                })
+                .await?;
+            match reply {
+                Ok(r) => break r.into_inner(),
+                Err(s) => self.before_retry(idempotent, attempts, started, s).await?,
             }
         };
 
-        let result = result?;
-
         if result.error.is_empty() {
             let v = serde_json::from_str::<R>(&result.data)?;
             Ok(v)
@@ -219,30 +318,28 @@ impl MetaGrpcClient {
         T: Into<MetaGrpcReadReq>,
         R: DeserializeOwned,
     {
+        let idempotent = T::idempotent();
         let act: MetaGrpcReadReq = v.into();
-        let req: Request<RaftRequest> = (&act.clone()).try_into()?;
-        let req = common_tracing::inject_span_to_tonic_request(req);
+        let _permit = self.acquire_inflight().await?;
 
-        let mut client = self.make_client().await?;
-        let result = client.read_msg(req).await;
-        let rpc_res: std::result::Result<RaftReply, Status> = match result {
-            Ok(r) => Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    {
-                        let mut token = self.token.write().await;
-                        *token = None;
-                    }
+        let started = Instant::now();
+        let mut attempts = 0;
+        let raft_reply = loop {
+            attempts += 1;
+            let req: Request<RaftRequest> = (&act).try_into()?;
+            let req = common_tracing::inject_span_to_tonic_request(req);
+
+            let reply = self
+                .timed(async {
                     let mut client = self.make_client().await?;
-                    let req: Request<RaftRequest> = (&act).try_into()?;
-                    let req = common_tracing::inject_span_to_tonic_request(req);
-                    Ok(client.read_msg(req).await?.into_inner())
-                } else {
-                    Err(s)
-                }
+                    Ok(client.read_msg(req).await)
+                })
+                .await?;
+            match reply {
+                Ok(r) => break r.into_inner(),
+                Err(s) => self.before_retry(idempotent, attempts, started, s).await?,
             }
         };
-        let raft_reply = rpc_res?;
 
         if raft_reply.error.is_empty() {
             let v = serde_json::from_str::<R>(&raft_reply.data)?;
@@ -252,10 +349,159 @@ impl MetaGrpcClient {
             Err(e.into())
         }
     }
+
+    /// List the kv of a prefix, the items are yielded as the pages arrive from the server,
+    /// instead of loading all of them into memory.
+    /// A server without `ListKV` replies all the items at once by a `PrefixListKV` read.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn list_kv_stream(
+        &self,
+        prefix: &str,
+    ) -> Result<BoxStream<'static, Result<(String, SeqV<Vec<u8>>)>>> {
+        let list_req = PrefixListReq(prefix.to_string());
+        let req = Request::new(RaftRequest {
+            data: serde_json::to_string(&list_req)?,
+        });
+        let req = common_tracing::inject_span_to_tonic_request(req);
+
+        // The stream holds the slot until it is dropped.
+        let permit = self.acquire_inflight().await?;
+        let mut client = self.make_client().await?;
+        let pages = match client.list_kv(req).await {
+            Ok(r) => r.into_inner(),
+            Err(s) if s.code() == Code::Unimplemented => {
+                drop(permit);
+                let items: PrefixListReply = self.do_read(list_req).await?;
+                return Ok(futures::stream::iter(items.into_iter().map(Ok)).boxed());
+            }
+            Err(s) => return Err(s.into()),
+        };
+
+        let items = pages
+            .map(|page| -> Result<PrefixListReply> {
+                let page = page?;
+                if page.error.is_empty() {
+                    Ok(serde_json::from_str::<PrefixListReply>(&page.data)?)
+                } else {
+                    let e: SerializedError = serde_json::from_str(&page.error)?;
+                    Err(e.into())
+                }
+            })
+            .map_ok(|items| futures::stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+            .map(move |item| {
+                let _inflight = &permit;
+                item
+            });
+        Ok(items.boxed())
+    }
+
+    // Wait for an in-flight slot, the returned permit releases the slot when dropped.
+    async fn acquire_inflight(&self) -> Result<Option<OwnedSemaphorePermit>> {
+        let inflight = match &self.inflight {
+            Some(inflight) => inflight.clone(),
+            None => return Ok(None),
+        };
+
+        if let Ok(permit) = inflight.clone().try_acquire_owned() {
+            return Ok(Some(permit));
+        }
+
+        increment_gauge!(METRIC_META_GRPC_CLIENT_QUEUED, 1.0);
+        let permit = inflight.acquire_owned().await;
+        decrement_gauge!(METRIC_META_GRPC_CLIENT_QUEUED, 1.0);
+
+        let permit = permit.map_err(|e| ErrorCode::LogicalError(format!("{}", e)))?;
+        Ok(Some(permit))
+    }
+
+    // An attempt not finished within request_timeout fails with DeadlineExceeded.
+    async fn timed<R>(
+        &self,
+        attempt: impl Future<Output = Result<std::result::Result<R, Status>>>,
+    ) -> Result<std::result::Result<R, Status>> {
+        let timeout = match self.request_timeout {
+            Some(timeout) => timeout,
+            None => return attempt.await,
+        };
+
+        match tokio::time::timeout(timeout, attempt).await {
+            Ok(reply) => reply,
+            Err(_) => Ok(Err(Status::deadline_exceeded(format!(
+                "meta rpc is not replied in {:?}",
+                timeout
+            )))),
+        }
+    }
+
+    // Prepare for the next attempt of a failed request, or return the error if it is not retried.
+    // A rejected token is renewed for any request, a transient failure is retried with
+    // exponential backoff only for an idempotent request.
+    // Both knobs are checked against the single `attempts` counter of the request, which counts
+    // every send: `max_token_renewals` bounds the sends while the token is being renewed, and
+    // `max_retries` bounds the retries of transient failures, thus the sends spent on one kind
+    // of failure are also counted against the other.
+    async fn before_retry(
+        &self,
+        idempotent: bool,
+        attempts: u32,
+        started: Instant,
+        status: Status,
+    ) -> Result<()> {
+        let (retryable, retry) = if status_is_retryable(&status, idempotent) {
+            (true, attempts < self.max_token_renewals)
+        } else if idempotent && status_is_transient(&status) {
+            (true, attempts <= self.max_retries)
+        } else {
+            (false, false)
+        };
+
+        if !retry {
+            return Err(match status.code() {
+                Code::DeadlineExceeded => ErrorCode::Timeout(format!(
+                    "meta rpc timed out after {} attempts in {:?}, last error: {}",
+                    attempts,
+                    started.elapsed(),
+                    status
+                )),
+                _ if retryable && attempts > 1 => retry_exhausted(attempts, started, status),
+                _ => status.into(),
+            });
+        }
+
+        if status_is_retryable(&status, idempotent) {
+            let mut token = self.token.write().await;
+            *token = None;
+        } else {
+            let backoff = META_RPC_RETRY_BACKOFF * 2u32.pow((attempts - 1).min(6));
+            tracing::debug!("retry meta rpc in {:?} after: {}", backoff, status);
+            tokio::time::sleep(backoff).await;
+        }
+        Ok(())
+    }
+}
+
+/// A request rejected for its token is not applied, thus it is sent again with a new token.
+/// An internal error may occur after a mutation is applied, thus only an idempotent request is sent again.
+fn status_is_retryable(status: &Status, idempotent: bool) -> bool {
+    match status.code() {
+        Code::Unauthenticated => true,
+        Code::Internal => idempotent,
+        _ => false,
+    }
+}
+
+fn status_is_transient(status: &Status) -> bool {
+    matches!(status.code(), Code::Unavailable | Code::DeadlineExceeded)
 }
 
-fn status_is_retryable(status: &Status) -> bool {
-    matches!(status.code(), Code::Unauthenticated | Code::Internal)
+fn retry_exhausted(attempts: u32, started: Instant, last_error: Status) -> ErrorCode {
+    ErrorCode::MetaRetryExhausted(format!(
+        "meta rpc retry exhausted after {} attempts in {:?}, last error: {}",
+        attempts,
+        started.elapsed(),
+        last_error
+    ))
 }
 
 #[derive(Clone)]
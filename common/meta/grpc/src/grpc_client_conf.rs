@@ -12,11 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use common_grpc::RpcClientConf;
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct MetaGrpcClientConf {
     pub meta_service_config: RpcClientConf,
     pub kv_service_config: RpcClientConf,
     pub client_timeout_in_second: u64,
+    /// Close the pooled connections idle for longer than it, 0 means never.
+    pub pool_idle_timeout_in_second: u64,
+    /// Store a checksum alongside each kv value and verify it on read.
+    pub kv_checksum: bool,
+    /// Fail a request not replied in time, including connecting and handshake, None means never.
+    pub request_timeout: Option<Duration>,
+    /// How many times an idempotent request is retried on a transient failure.
+    pub max_retries: u32,
+    /// How many times a request is sent at most when the token is renewed, 0 means the default.
+    pub max_token_renewals: u32,
+    /// How many requests are in flight at most, the excess ones wait for a slot, 0 means unbounded.
+    pub max_concurrent_requests: usize,
 }
@@ -0,0 +1,145 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio::sync::Mutex;
+use common_base::Runtime;
+use common_base::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_tracing::tracing;
+
+use crate::MetaGrpcClient;
+use crate::MetaGrpcClientConf;
+
+/// How many distinct configs a pool keeps clients for by default.
+pub const META_CLIENT_POOL_DEFAULT_CAPACITY: usize = 16;
+
+/// Shares `MetaGrpcClient`s among the users of the same `MetaGrpcClientConf`.
+///
+/// A shared client keeps its channels warm across requests,
+/// and its channel pool `check()`s a channel before reusing it,
+/// thus a broken channel is evicted and rebuilt.
+/// A shared client is `ping()`ed before it is handed out,
+/// a client failing the ping is evicted and rebuilt.
+/// When the pool is full, the client acquired least recently is dropped.
+///
+/// Clients are built on a runtime owned by the pool,
+/// thus the background tasks a client spawns, e.g., the idle channel reaper,
+/// keep running after the runtime of the caller that first acquired it is dropped.
+pub struct MetaGrpcClientPool {
+    capacity: usize,
+    inner: Mutex<PoolInner>,
+}
+
+struct PoolInner {
+    /// Created on the first client build.
+    runtime: Option<Arc<Runtime>>,
+    /// Ordered by the last acquiring time, the most recent is the last.
+    clients: Vec<(MetaGrpcClientConf, Arc<MetaGrpcClient>)>,
+}
+
+impl Default for MetaGrpcClientPool {
+    fn default() -> Self {
+        Self::new(META_CLIENT_POOL_DEFAULT_CAPACITY)
+    }
+}
+
+impl MetaGrpcClientPool {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(PoolInner {
+                runtime: None,
+                clients: vec![],
+            }),
+        }
+    }
+
+    /// Returns the client shared by `conf`, creates one if there is not or the shared one fails to ping.
+    ///
+    /// The client is pinged and built without holding the lock,
+    /// thus a slow connection does not block acquiring clients of other configs.
+    /// If another caller inserted a client for `conf` meanwhile, that one is shared
+    /// and the newly built one is dropped.
+    pub async fn acquire(&self, conf: &MetaGrpcClientConf) -> Result<Arc<MetaGrpcClient>> {
+        let cached = {
+            let mut inner = self.inner.lock().await;
+            Self::touch(&mut inner.clients, conf)
+        };
+
+        if let Some(client) = cached {
+            match client.ping().await {
+                Ok(_) => return Ok(client),
+                Err(e) => {
+                    tracing::warn!("evict the shared meta client failing to ping: {}", e);
+                    let mut inner = self.inner.lock().await;
+                    inner.clients.retain(|(_, c)| !Arc::ptr_eq(c, &client));
+                }
+            }
+        }
+
+        let runtime = {
+            let mut inner = self.inner.lock().await;
+            match &inner.runtime {
+                Some(runtime) => runtime.clone(),
+                None => {
+                    let runtime = Arc::new(Runtime::with_worker_threads(1)?);
+                    inner.runtime = Some(runtime.clone());
+                    runtime
+                }
+            }
+        };
+
+        let build_conf = conf.clone();
+        let client = runtime
+            .spawn(async move { MetaGrpcClient::try_new(&build_conf).await })
+            .await
+            .map_err(|e| ErrorCode::TokioError(format!("build meta client: {}", e)))??;
+        let client = Arc::new(client);
+
+        let mut inner = self.inner.lock().await;
+        if let Some(client) = Self::touch(&mut inner.clients, conf) {
+            return Ok(client);
+        }
+
+        if inner.clients.len() >= self.capacity {
+            inner.clients.remove(0);
+        }
+        inner.clients.push((conf.clone(), client.clone()));
+        Ok(client)
+    }
+
+    /// Moves the client of `conf` to the most recent position and returns it.
+    fn touch(
+        clients: &mut Vec<(MetaGrpcClientConf, Arc<MetaGrpcClient>)>,
+        conf: &MetaGrpcClientConf,
+    ) -> Option<Arc<MetaGrpcClient>> {
+        let i = clients.iter().position(|(c, _)| c == conf)?;
+        let entry = clients.remove(i);
+        let client = entry.1.clone();
+        clients.push(entry);
+        Some(client)
+    }
+
+    /// The number of configs that have a shared client.
+    pub async fn len(&self) -> usize {
+        self.inner.lock().await.clients.len()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
@@ -13,15 +13,19 @@
 // limitations under the License.
 
 use common_meta_api::KVApi;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::PrefixListReply;
+use common_meta_types::SeqV;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
+use futures::stream::BoxStream;
+use futures::TryStreamExt;
 
 use crate::grpc_action::GetKVAction;
 use crate::grpc_action::MGetKVAction;
-use crate::grpc_action::PrefixListReq;
 use crate::MetaGrpcClient;
 
 #[tonic::async_trait]
@@ -46,6 +50,23 @@ impl KVApi for MetaGrpcClient {
     }
 
     async fn prefix_list_kv(&self, prefix: &str) -> common_exception::Result<PrefixListReply> {
-        self.do_read(PrefixListReq(prefix.to_string())).await
+        MetaGrpcClient::list_kv_stream(self, prefix)
+            .await?
+            .try_collect()
+            .await
+    }
+
+    async fn list_kv_stream(
+        &self,
+        prefix: &str,
+    ) -> common_exception::Result<
+        BoxStream<'static, common_exception::Result<(String, SeqV<Vec<u8>>)>>,
+    > {
+        MetaGrpcClient::list_kv_stream(self, prefix).await
+    }
+
+    // Compared and swapped by the meta server, in one round trip.
+    async fn cas_kv(&self, act: CasKVAction) -> common_exception::Result<CasKVReply> {
+        self.do_write(act).await
     }
 }
@@ -15,12 +15,18 @@
 mod grpc_action;
 mod grpc_client;
 mod grpc_client_conf;
+mod grpc_client_pool;
 mod kv_api_impl;
 mod meta_api_impl;
 
 pub use grpc_action::GetTableExtReq;
 pub use grpc_action::MetaGrpcReadReq;
 pub use grpc_action::MetaGrpcWriteReq;
+pub use grpc_action::PrefixListReq;
 pub use grpc_action::RequestFor;
 pub use grpc_client::MetaGrpcClient;
+pub use grpc_client::META_RPC_MAX_TOKEN_RENEWALS;
+pub use grpc_client::METRIC_META_GRPC_CLIENT_QUEUED;
 pub use grpc_client_conf::MetaGrpcClientConf;
+pub use grpc_client_pool::MetaGrpcClientPool;
+pub use grpc_client_pool::META_CLIENT_POOL_DEFAULT_CAPACITY;
@@ -12,14 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
 
 use common_base::tokio;
+use common_exception::ErrorCode;
+use common_meta_api::KVApi;
 use common_meta_api::MetaApi;
 use common_meta_grpc::MetaGrpcClient;
+use common_meta_grpc::META_RPC_MAX_TOKEN_RENEWALS;
+use common_meta_types::CreateDatabaseReq;
 use common_meta_types::GetDatabaseReq;
+use tonic::Code;
 
 use crate::grpc_server::start_grpc_server;
+use crate::grpc_server::start_mock_server;
+use crate::grpc_server::MockMetaService;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_grpc_client_action_timeout() {
@@ -55,3 +65,208 @@ async fn test_grpc_client_handshake_timeout() {
     let expect = "status: Cancelled, message: \"Timeout expired\", details: [], metadata: MetadataMap { headers: {} }";
     assert_eq!(actual, expect);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_retry_exhausted() {
+    let srv_addr = start_mock_server(MockMetaService::default().with_failure(
+        usize::MAX,
+        Code::Internal,
+        "Always fail",
+    ));
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap();
+
+    let expect = format!(
+        "meta rpc retry exhausted after {} attempts in",
+        META_RPC_MAX_TOKEN_RENEWALS
+    );
+
+    let err = client
+        .get_database(GetDatabaseReq::new("tenant1", "xx"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::meta_retry_exhausted_code());
+    assert!(err.message().starts_with(&expect), "{}", err.message());
+    assert!(err.message().contains("Always fail"), "{}", err.message());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_internal_error_retry_idempotent_only() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let srv_addr = start_mock_server(
+        MockMetaService::default()
+            .with_failure(usize::MAX, Code::Internal, "Always fail")
+            .with_calls(calls.clone()),
+    );
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap()
+        .with_max_token_renewals(3);
+
+    // read: sent at most `max_token_renewals` times.
+    let err = client
+        .get_database(GetDatabaseReq::new("tenant1", "xx"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::meta_retry_exhausted_code());
+    assert!(
+        err.message()
+            .starts_with("meta rpc retry exhausted after 3 attempts in"),
+        "{}",
+        err.message()
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // write: an internal error may occur after it is applied, never sent again.
+    calls.store(0, Ordering::SeqCst);
+    let err = client
+        .create_database(CreateDatabaseReq {
+            if_not_exists: false,
+            tenant: "tenant1".to_string(),
+            db: "xx".to_string(),
+            meta: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert_ne!(err.code(), ErrorCode::meta_retry_exhausted_code());
+    assert!(err.message().contains("Always fail"), "{}", err.message());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_retry_then_succeed() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let srv_addr = start_mock_server(
+        MockMetaService::default()
+            .with_failure(1, Code::Internal, "Fail once")
+            .with_calls(calls.clone()),
+    );
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap();
+
+    let reply = client.get_kv("k").await.unwrap();
+    assert!(reply.is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_request_timeout() {
+    // The handshake of the mock server takes 2 seconds.
+    let srv_addr = start_grpc_server();
+
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", None, None)
+        .await
+        .unwrap()
+        .with_request_timeout(Some(Duration::from_secs(1)));
+
+    let err = client
+        .get_database(GetDatabaseReq::new("tenant1", "xx"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::timeout_code());
+    assert!(
+        err.message()
+            .starts_with("meta rpc timed out after 1 attempts"),
+        "{}",
+        err.message()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_retry_idempotent_only() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let srv_addr = start_mock_server(
+        MockMetaService::default()
+            .with_failure(usize::MAX, Code::Unavailable, "Unavailable")
+            .with_calls(calls.clone()),
+    );
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap()
+        .with_max_retries(2);
+
+    // read: the first attempt and 2 retries.
+    let err = client
+        .get_database(GetDatabaseReq::new("tenant1", "xx"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::meta_retry_exhausted_code());
+    assert!(err
+        .message()
+        .starts_with("meta rpc retry exhausted after 3 attempts in"));
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+
+    // write: never retried.
+    calls.store(0, Ordering::Relaxed);
+    let err = client
+        .create_database(CreateDatabaseReq {
+            if_not_exists: false,
+            tenant: "tenant1".to_string(),
+            db: "xx".to_string(),
+            meta: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert_ne!(err.code(), ErrorCode::meta_retry_exhausted_code());
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_ping() {
+    let srv_addr = start_grpc_server();
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap();
+
+    let latency = client.ping().await.unwrap();
+    assert!(latency > Duration::ZERO);
+
+    // Nothing listens on this port.
+    let client = MetaGrpcClient::try_create("127.0.0.1:1", "", "", Some(timeout), None)
+        .await
+        .unwrap();
+    let err = client.ping().await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::cannot_connect_node_code());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_grpc_client_max_concurrent_requests() {
+    let max_inflight = Arc::new(AtomicUsize::new(0));
+    let srv_addr = start_mock_server(
+        MockMetaService::default()
+            .with_delay(Duration::from_millis(50))
+            .with_max_inflight(max_inflight.clone()),
+    );
+
+    let timeout = Duration::from_secs(3);
+    let client = MetaGrpcClient::try_create(&srv_addr, "", "", Some(timeout), None)
+        .await
+        .unwrap()
+        .with_max_concurrent_requests(3);
+
+    let keys = (0..20).map(|i| format!("k{}", i)).collect::<Vec<_>>();
+    let requests = keys.iter().map(|k| client.get_kv(k));
+    let replies = futures::future::join_all(requests).await;
+
+    // All the excess requests are queued and complete eventually.
+    assert_eq!(replies.len(), 20);
+    for r in replies {
+        assert!(r.unwrap().is_none());
+    }
+
+    let max = max_inflight.load(Ordering::SeqCst);
+    assert!(max >= 1, "max in-flight: {}", max);
+    assert!(max <= 3, "max in-flight: {}", max);
+}
@@ -0,0 +1,152 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::Runtime;
+use common_exception::Result;
+use common_meta_grpc::MetaGrpcClientConf;
+use common_meta_grpc::MetaGrpcClientPool;
+
+use crate::grpc_server::start_mock_server;
+use crate::grpc_server::MockMetaService;
+
+fn conf_of(addr: &str) -> MetaGrpcClientConf {
+    let mut conf = MetaGrpcClientConf::default();
+    conf.meta_service_config.address = addr.to_string();
+    conf.kv_service_config.address = addr.to_string();
+    conf.client_timeout_in_second = 1;
+    conf
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_pool_share_and_evict() -> Result<()> {
+    // A shared client is pinged before it is handed out, the servers must be up.
+    let addr_a = start_mock_server(MockMetaService::default());
+    let addr_b = start_mock_server(MockMetaService::default());
+    let addr_c = start_mock_server(MockMetaService::default());
+
+    let pool = MetaGrpcClientPool::new(2);
+    assert!(pool.is_empty().await);
+
+    let a1 = pool.acquire(&conf_of(&addr_a)).await?;
+    let a2 = pool.acquire(&conf_of(&addr_a)).await?;
+    assert!(Arc::ptr_eq(&a1, &a2), "the same conf shares a client");
+    assert_eq!(1, pool.len().await);
+
+    let b1 = pool.acquire(&conf_of(&addr_b)).await?;
+    assert!(
+        !Arc::ptr_eq(&a1, &b1),
+        "a different conf gets its own client"
+    );
+    assert_eq!(2, pool.len().await);
+
+    // Touch "a", then "b" becomes the least recently acquired one and is evicted.
+    pool.acquire(&conf_of(&addr_a)).await?;
+    pool.acquire(&conf_of(&addr_c)).await?;
+    assert_eq!(2, pool.len().await);
+
+    let a3 = pool.acquire(&conf_of(&addr_a)).await?;
+    assert!(Arc::ptr_eq(&a1, &a3));
+
+    let b2 = pool.acquire(&conf_of(&addr_b)).await?;
+    assert!(!Arc::ptr_eq(&b1, &b2), "an evicted client is not reused");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_grpc_client_pool_concurrent_acquire() -> Result<()> {
+    let addr = start_mock_server(MockMetaService::default());
+    let pool = Arc::new(MetaGrpcClientPool::new(2));
+
+    let mut handles = vec![];
+    for _ in 0..8 {
+        let pool = pool.clone();
+        let conf = conf_of(&addr);
+        handles.push(tokio::spawn(async move { pool.acquire(&conf).await }));
+    }
+
+    let mut clients = vec![];
+    for h in handles {
+        clients.push(h.await.unwrap()?);
+    }
+
+    for c in clients.iter() {
+        assert!(
+            Arc::ptr_eq(&clients[0], c),
+            "concurrent acquiring shares one client"
+        );
+    }
+    assert_eq!(1, pool.len().await);
+
+    Ok(())
+}
+
+#[test]
+fn test_grpc_client_pool_outlives_caller_runtime() -> Result<()> {
+    // The server outlives the caller runtimes.
+    let server_rt = Runtime::with_worker_threads(1)?;
+    let addr = server_rt.block_on(async { start_mock_server(MockMetaService::default()) });
+
+    let pool = Arc::new(MetaGrpcClientPool::new(2));
+
+    let mut conf = conf_of(&addr);
+    conf.pool_idle_timeout_in_second = 1;
+
+    let caller_rt = Runtime::with_worker_threads(1)?;
+    let a1 = caller_rt.block_on(pool.acquire(&conf))?;
+    drop(caller_rt);
+
+    let another_rt = Runtime::with_worker_threads(1)?;
+    let a2 = another_rt.block_on(pool.acquire(&conf))?;
+    assert!(
+        Arc::ptr_eq(&a1, &a2),
+        "a client is still shared after the runtime that built it is dropped"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grpc_client_pool_evict_unreachable() -> Result<()> {
+    let ping_unavailable = Arc::new(AtomicBool::new(false));
+    let addr = start_mock_server(
+        MockMetaService::default().with_ping_unavailable(ping_unavailable.clone()),
+    );
+    let conf = conf_of(&addr);
+
+    let pool = MetaGrpcClientPool::new(2);
+    let a1 = pool.acquire(&conf).await?;
+    let a2 = pool.acquire(&conf).await?;
+    assert!(Arc::ptr_eq(&a1, &a2));
+
+    // The shared client fails to ping, it is evicted and a new one is built.
+    ping_unavailable.store(true, Ordering::SeqCst);
+    let a3 = pool.acquire(&conf).await?;
+    assert!(
+        !Arc::ptr_eq(&a1, &a3),
+        "a client failing to ping is not reused"
+    );
+    assert_eq!(1, pool.len().await);
+
+    ping_unavailable.store(false, Ordering::SeqCst);
+    let a4 = pool.acquire(&conf).await?;
+    assert!(Arc::ptr_eq(&a3, &a4), "the rebuilt client is shared");
+
+    Ok(())
+}
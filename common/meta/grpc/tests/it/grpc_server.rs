@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use std::pin::Pin;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -20,28 +24,116 @@ use common_base::tokio;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::meta_service_server::MetaServiceServer;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::PingRequest;
+use common_meta_types::protobuf::PingResponse;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use futures::Stream;
 use rand::Rng;
 use tonic::transport::Server;
+use tonic::Code;
 use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
-pub struct GrpcServiceForTestImpl {}
+/// A mock meta service, the reads and writes fail or reply an absent value as configured.
+///
+/// The read/write calls are counted and the most concurrent ones ever seen are recorded.
+pub struct MockMetaService {
+    handshake_delay: Duration,
+    /// How long a read or write takes before it replies.
+    delay: Duration,
+    /// How many reads or writes fail, the following ones succeed.
+    fail_times: usize,
+    fail_status: (Code, String),
+    calls: Arc<AtomicUsize>,
+    inflight: AtomicUsize,
+    max_inflight: Arc<AtomicUsize>,
+    /// The pings fail with `Unavailable` while it is set.
+    ping_unavailable: Arc<AtomicBool>,
+}
+
+impl Default for MockMetaService {
+    fn default() -> Self {
+        Self {
+            handshake_delay: Duration::ZERO,
+            delay: Duration::ZERO,
+            fail_times: 0,
+            fail_status: (Code::Internal, "".to_string()),
+            calls: Arc::new(AtomicUsize::new(0)),
+            inflight: AtomicUsize::new(0),
+            max_inflight: Arc::new(AtomicUsize::new(0)),
+            ping_unavailable: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl MockMetaService {
+    pub fn with_handshake_delay(mut self, delay: Duration) -> Self {
+        self.handshake_delay = delay;
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Fail the first `times` reads or writes with `code`, `usize::MAX` means always.
+    pub fn with_failure(mut self, times: usize, code: Code, message: &str) -> Self {
+        self.fail_times = times;
+        self.fail_status = (code, message.to_string());
+        self
+    }
+
+    pub fn with_calls(mut self, calls: Arc<AtomicUsize>) -> Self {
+        self.calls = calls;
+        self
+    }
+
+    pub fn with_max_inflight(mut self, max_inflight: Arc<AtomicUsize>) -> Self {
+        self.max_inflight = max_inflight;
+        self
+    }
+
+    pub fn with_ping_unavailable(mut self, ping_unavailable: Arc<AtomicBool>) -> Self {
+        self.ping_unavailable = ping_unavailable;
+        self
+    }
+
+    async fn reply(&self) -> Result<Response<RaftReply>, Status> {
+        let nth = self.calls.fetch_add(1, Ordering::SeqCst);
+
+        let n = self.inflight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_inflight.fetch_max(n, Ordering::SeqCst);
+        tokio::time::sleep(self.delay).await;
+        self.inflight.fetch_sub(1, Ordering::SeqCst);
+
+        if nth < self.fail_times {
+            let (code, message) = &self.fail_status;
+            return Err(Status::new(*code, message));
+        }
+
+        Ok(Response::new(RaftReply {
+            data: "null".to_string(),
+            error: "".to_string(),
+        }))
+    }
+}
 
 #[tonic::async_trait]
-impl MetaService for GrpcServiceForTestImpl {
+impl MetaService for MockMetaService {
     type HandshakeStream =
         Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send + Sync + 'static>>;
+    type ListKVStream =
+        Pin<Box<dyn Stream<Item = Result<RaftReply, Status>> + Send + Sync + 'static>>;
 
     async fn handshake(
         &self,
         _request: Request<Streaming<common_meta_types::protobuf::HandshakeRequest>>,
     ) -> Result<Response<Self::HandshakeStream>, Status> {
-        tokio::time::sleep(Duration::from_secs(2)).await;
+        tokio::time::sleep(self.handshake_delay).await;
         let output = futures::stream::once(async { Ok(HandshakeResponse::default()) });
         Ok(Response::new(Box::pin(output)))
     }
@@ -50,24 +142,44 @@ impl MetaService for GrpcServiceForTestImpl {
         &self,
         _request: Request<RaftRequest>,
     ) -> Result<Response<RaftReply>, Status> {
-        Err(Status::unimplemented("Not yet implemented"))
+        self.reply().await
     }
 
     async fn read_msg(
         &self,
         _request: Request<RaftRequest>,
     ) -> Result<Response<RaftReply>, Status> {
-        // for timeout test
-        tokio::time::sleep(Duration::from_secs(60)).await;
+        self.reply().await
+    }
+
+    async fn list_kv(
+        &self,
+        _request: Request<RaftRequest>,
+    ) -> Result<Response<Self::ListKVStream>, Status> {
         Err(Status::unimplemented("Not yet implemented"))
     }
+
+    async fn ping(&self, _request: Request<PingRequest>) -> Result<Response<PingResponse>, Status> {
+        if self.ping_unavailable.load(Ordering::SeqCst) {
+            return Err(Status::unavailable("ping unavailable"));
+        }
+        Ok(Response::new(PingResponse {}))
+    }
 }
 
+/// Start a mock server whose handshake takes 2 seconds and every read or write takes 60 seconds.
 pub fn start_grpc_server() -> String {
+    start_mock_server(
+        MockMetaService::default()
+            .with_handshake_delay(Duration::from_secs(2))
+            .with_delay(Duration::from_secs(60)),
+    )
+}
+
+pub fn start_mock_server(service: MockMetaService) -> String {
     let mut rng = rand::thread_rng();
     let port = rng.gen_range(10000..20000);
     let addr = format!("127.0.0.1:{}", port).parse().unwrap();
-    let service = GrpcServiceForTestImpl {};
 
     let svc = MetaServiceServer::new(service);
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 mod grpc_client;
+mod grpc_client_pool;
 mod grpc_server;
 
 pub use grpc_server::start_grpc_server;
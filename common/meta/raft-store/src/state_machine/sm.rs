@@ -565,6 +565,36 @@ impl StateMachine {
         Ok(Change::new(prev, result).into())
     }
 
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_cas_kv_cmd(
+        &self,
+        key: &str,
+        expected: &Option<Vec<u8>>,
+        value: &Option<Vec<u8>>,
+
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+        let key = key.to_string();
+
+        // If prev is timed out, treat it as a None.
+        let prev = Self::unexpired_opt(sub_tree.get(&key)?);
+        if prev.as_ref().map(|v| &v.data) != expected.as_ref() {
+            return Ok(Change::new(prev.clone(), prev).into());
+        }
+
+        let value_op = match value {
+            Some(v) => Operation::Update(v.clone()),
+            None => Operation::Delete,
+        };
+        let value_meta = prev.as_ref().and_then(|v| v.meta.clone());
+        let result =
+            self.sub_txn_tree_do_update(&sub_tree, &key, prev.clone(), value_meta, value_op)?;
+
+        tracing::debug!("applied CasKV: {} {:?}", key, result);
+        Ok(Change::new(prev, result).into())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_upsert_table_options_cmd(
         &self,
@@ -667,6 +697,12 @@ impl StateMachine {
                 value_meta,
             } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, txn_tree),
 
+            Cmd::CasKV {
+                key,
+                expected,
+                value,
+            } => self.apply_cas_kv_cmd(key, expected, value, txn_tree),
+
             Cmd::UpsertTableOptions(ref req) => self.apply_upsert_table_options_cmd(req, txn_tree),
         }
     }
@@ -1016,6 +1052,41 @@ impl StateMachine {
         }
     }
 
+    /// List at most `limit` unexpired kv of a prefix, from the key after `start_after`.
+    ///
+    /// A page with less than `limit` items is the last page.
+    pub fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> common_exception::Result<Vec<(String, SeqV<Vec<u8>>)>> {
+        let kvs = self.kvs();
+        let prefix = prefix.to_string();
+        let mut start_after = start_after.map(|k| k.to_string());
+        let mut res = vec![];
+
+        // Expired items are skipped, scan again until the page is full or the prefix is exhausted.
+        while res.len() < limit {
+            let want = limit - res.len();
+            let scanned = kvs.scan_prefix_page(&prefix, start_after.as_ref(), want)?;
+            let exhausted = scanned.len() < want;
+            start_after = scanned.last().map(|(k, _)| k.clone());
+
+            res.extend(
+                scanned
+                    .into_iter()
+                    .filter_map(|(k, v)| Self::unexpired(v).map(|v| (k, v))),
+            );
+
+            if exhausted {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn lookup_table_id(
         &self,
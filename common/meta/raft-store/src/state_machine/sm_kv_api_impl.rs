@@ -14,6 +14,8 @@
 
 use common_meta_api::KVApi;
 use common_meta_types::AppliedState;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::MGetKVActionReply;
@@ -50,6 +52,26 @@ impl KVApi for StateMachine {
         }
     }
 
+    async fn cas_kv(&self, act: CasKVAction) -> common_exception::Result<CasKVReply> {
+        let cmd = Cmd::CasKV {
+            key: act.key,
+            expected: act.expected.clone(),
+            value: act.value,
+        };
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+
+        match res {
+            AppliedState::KV(x) => Ok(CasKVReply::from_change(&act.expected, x)),
+            _ => {
+                panic!("expect AppliedState::KV");
+            }
+        }
+    }
+
     async fn get_kv(&self, key: &str) -> common_exception::Result<GetKVActionReply> {
         // TODO(xp) refine get(): a &str is enough for key
         let sv = self.kvs().get(&key.to_string())?;
@@ -622,6 +622,90 @@ async fn test_state_machine_apply_non_dup_generic_kv_value_meta() -> anyhow::Res
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_cas_kv() -> anyhow::Result<()> {
+    // - A cas with a mismatched expected value changes nothing.
+    // - A cas with the matched expected value swaps the value and keeps the value-meta.
+
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let key = "cas_foo".to_string();
+    let meta = Some(KVMeta {
+        expire_at: Some(now + 10),
+    });
+
+    sm.sm_tree.txn(true, |t| {
+        Ok(sm
+            .apply_cmd(
+                &Cmd::UpsertKV {
+                    key: key.clone(),
+                    seq: MatchSeq::Any,
+                    value: Operation::Update(b"v1".to_vec()),
+                    value_meta: meta.clone(),
+                },
+                &t,
+            )
+            .unwrap())
+    })?;
+    let prev = sm.get_kv(&key).await?;
+
+    tracing::info!("--- cas with a mismatched expected value");
+
+    let resp = sm.sm_tree.txn(true, |t| {
+        Ok(sm
+            .apply_cmd(
+                &Cmd::CasKV {
+                    key: key.clone(),
+                    expected: Some(b"v0".to_vec()),
+                    value: Some(b"v2".to_vec()),
+                },
+                &t,
+            )
+            .unwrap())
+    })?;
+    assert_eq!(
+        AppliedState::KV(Change::new(prev.clone(), prev.clone())),
+        resp
+    );
+
+    tracing::info!("--- cas with the matched expected value");
+
+    let resp = sm.sm_tree.txn(true, |t| {
+        Ok(sm
+            .apply_cmd(
+                &Cmd::CasKV {
+                    key: key.clone(),
+                    expected: Some(b"v1".to_vec()),
+                    value: Some(b"v2".to_vec()),
+                },
+                &t,
+            )
+            .unwrap())
+    })?;
+
+    let got = sm.get_kv(&key).await?.unwrap();
+    assert_eq!(
+        SeqV {
+            seq: got.seq,
+            meta,
+            data: b"v2".to_vec()
+        },
+        got
+    );
+    assert_eq!(AppliedState::KV(Change::new(prev, Some(got))), resp);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_delete() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
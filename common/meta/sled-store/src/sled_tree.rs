@@ -361,6 +361,51 @@ impl SledTree {
         Ok(res)
     }
 
+    /// Get at most `limit` key-values with the same prefix, from the key after `start_after`
+    pub fn scan_prefix_page<KV>(
+        &self,
+        prefix: &KV::K,
+        start_after: Option<&KV::K>,
+        limit: usize,
+    ) -> common_exception::Result<Vec<(KV::K, KV::V)>>
+    where
+        KV: SledKeySpace,
+    {
+        let mut res = vec![];
+
+        let mes = || format!("scan_prefix_page: {}", prefix);
+
+        let pref = KV::serialize_key(prefix)?;
+        let start = match start_after {
+            Some(k) => {
+                let k = KV::serialize_key(k)?;
+                if k >= pref {
+                    Bound::Excluded(k)
+                } else {
+                    Bound::Included(pref.clone())
+                }
+            }
+            None => Bound::Included(pref.clone()),
+        };
+
+        for item in self.tree.range::<sled::IVec, _>((start, Bound::Unbounded)) {
+            if res.len() >= limit {
+                break;
+            }
+
+            let (k, v) = item.map_err_to_code(ErrorCode::MetaStoreDamaged, mes)?;
+            if !k.starts_with(&pref) {
+                break;
+            }
+
+            let key = KV::deserialize_key(k)?;
+            let value = KV::deserialize_value(v)?;
+            res.push((key, value));
+        }
+
+        Ok(res)
+    }
+
     /// Get values of key in `range`
     pub fn range_values<KV, R>(&self, range: R) -> common_exception::Result<Vec<KV::V>>
     where
@@ -692,6 +737,16 @@ impl<'a, KV: SledKeySpace> AsKeySpace<'a, KV> {
         self.inner.scan_prefix::<KV>(prefix)
     }
 
+    pub fn scan_prefix_page(
+        &self,
+        prefix: &KV::K,
+        start_after: Option<&KV::K>,
+        limit: usize,
+    ) -> common_exception::Result<Vec<(KV::K, KV::V)>> {
+        self.inner
+            .scan_prefix_page::<KV>(prefix, start_after, limit)
+    }
+
     pub fn range_values<R>(&self, range: R) -> common_exception::Result<Vec<KV::V>>
     where R: RangeBounds<KV::K> {
         self.inner.range_values::<KV, R>(range)
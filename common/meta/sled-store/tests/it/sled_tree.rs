@@ -379,6 +379,43 @@ async fn test_sled_tree_scan_prefix() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sled_tree_scan_prefix_page() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_sled_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_sled_test_context();
+    let db = &tc.db;
+    let tree = SledTree::open(db, tc.tree_name, true)?;
+
+    let files: Vec<(String, String)> = vec![
+        ("a".to_string(), "x".to_string()),
+        ("ab".to_string(), "xy".to_string()),
+        ("abc".to_string(), "xyz".to_string()),
+        ("abd".to_string(), "xyZ".to_string()),
+        ("b".to_string(), "y".to_string()),
+    ];
+
+    tree.append::<Files>(&files).await?;
+
+    let prefix = "ab".to_string();
+
+    let got = tree.scan_prefix_page::<Files>(&prefix, None, 2)?;
+    assert_eq!(files[1..3], got);
+
+    let got = tree.scan_prefix_page::<Files>(&prefix, Some(&"abc".to_string()), 2)?;
+    assert_eq!(files[3..4], got);
+
+    let got = tree.scan_prefix_page::<Files>(&prefix, Some(&"abd".to_string()), 2)?;
+    assert!(got.is_empty());
+
+    // A start before the prefix starts from the prefix.
+    let got = tree.scan_prefix_page::<Files>(&prefix, Some(&"a".to_string()), 10)?;
+    assert_eq!(files[1..4], got);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_sled_tree_insert() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_sled_ut!();
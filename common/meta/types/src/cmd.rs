@@ -87,6 +87,17 @@ pub enum Cmd {
         /// Meta data of a value.
         value_meta: Option<KVMeta>,
     },
+
+    /// Compare-and-swap a general purpose kv: replace the value of `key` by `value` only if the
+    /// current value is `expected`, in one apply.
+    ///
+    /// A `None` expected means the key is absent, a `None` value deletes the key.
+    /// The meta data of the swapped value is kept.
+    CasKV {
+        key: String,
+        expected: Option<Vec<u8>>,
+        value: Option<Vec<u8>>,
+    },
 }
 
 impl fmt::Display for Cmd {
@@ -135,6 +146,13 @@ impl fmt::Display for Cmd {
                     key, seq, value, value_meta
                 )
             }
+            Cmd::CasKV {
+                key,
+                expected,
+                value,
+            } => {
+                write!(f, "cas_kv: {} = {:?} -> {:?}", key, expected, value)
+            }
             Cmd::UpsertTableOptions(req) => {
                 write!(
                     f,
@@ -31,6 +31,12 @@ pub struct MGetKVReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct ListKVReq {
     pub prefix: String,
+    /// List from the key after it, to list a prefix page by page.
+    #[serde(default)]
+    pub start_after: Option<String>,
+    /// The max number of items to list, all of them if it is None.
+    #[serde(default)]
+    pub limit: Option<usize>,
 }
 
 pub type UpsertKVActionReply = Change<Vec<u8>>;
@@ -61,3 +67,40 @@ impl UpsertKVAction {
         }
     }
 }
+
+/// Replace the value of `key` by `value` only if its current value is `expected`,
+/// `None` means the key is absent, or deletes the key.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CasKVAction {
+    pub key: String,
+    pub expected: Option<Vec<u8>>,
+    pub value: Option<Vec<u8>>,
+}
+
+impl CasKVAction {
+    pub fn new(key: &str, expected: Option<Vec<u8>>, value: Option<Vec<u8>>) -> Self {
+        Self {
+            key: key.to_string(),
+            expected,
+            value,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CasKVReply {
+    /// Whether the value is swapped.
+    pub success: bool,
+    /// The value before the swap, or the current value if the swap failed.
+    pub prev: Option<SeqV<Vec<u8>>>,
+}
+
+impl CasKVReply {
+    /// Build the reply from the change applied by a cas expecting `expected`.
+    pub fn from_change(expected: &Option<Vec<u8>>, change: Change<Vec<u8>>) -> Self {
+        Self {
+            success: change.prev.as_ref().map(|v| &v.data) == expected.as_ref(),
+            prev: change.prev,
+        }
+    }
+}
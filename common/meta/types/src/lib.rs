@@ -64,6 +64,8 @@ pub use database::DropDatabaseReq;
 pub use database::GetDatabaseReq;
 pub use database::ListDatabaseReq;
 pub use errors::ConflictSeq;
+pub use kv_message::CasKVAction;
+pub use kv_message::CasKVReply;
 pub use kv_message::GetKVActionReply;
 pub use kv_message::GetKVReq;
 pub use kv_message::ListKVReq;
@@ -96,6 +96,9 @@ pub use plan_expression::ExpressionPlan;
 pub use plan_expression::Expressions;
 pub use plan_expression_action::*;
 pub use plan_expression_chain::ExpressionChain;
+pub use plan_expression_chain::ExpressionLimits;
+pub use plan_expression_chain::DEFAULT_MAX_EXPRESSION_ACTIONS;
+pub use plan_expression_chain::DEFAULT_MAX_EXPRESSION_DEPTH;
 pub use plan_expression_column::col;
 pub use plan_expression_common::expand_aggregate_arg_exprs;
 pub use plan_expression_common::expand_wildcard;
@@ -17,7 +17,9 @@ use std::fmt;
 use common_datavalues::DataType;
 use common_datavalues::DataTypeAndNullable;
 use common_datavalues::DataValue;
+use common_exception::Result;
 use common_functions::scalars::Function;
+use common_functions::scalars::FunctionFactory;
 
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
@@ -27,6 +29,8 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    /// Equivalent to an earlier function action, reuses its computed column.
+    Reuse(ActionReuse),
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +53,14 @@ pub struct ActionAlias {
     pub arg_type: DataType,
 }
 
+#[derive(Debug, Clone)]
+pub struct ActionReuse {
+    pub name: String,
+    pub arg_name: String,
+    pub return_type: DataType,
+    pub is_nullable: bool,
+}
+
 #[derive(Clone)]
 pub struct ActionFunction {
     pub name: String,
@@ -69,7 +81,21 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::Reuse(r) => &r.name,
+        }
+    }
+}
+
+impl ActionFunction {
+    /// Whether the function always returns the same output for the same input, read from
+    /// the registered features. The cast action is not registered, it is always deterministic.
+    pub fn is_deterministic(&self) -> Result<bool> {
+        if self.func_name == "cast" {
+            return Ok(true);
         }
+
+        let features = FunctionFactory::instance().get_features(&self.func_name)?;
+        Ok(features.is_deterministic)
     }
 }
 
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataTypeAndNullable;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::CastFunction;
@@ -22,6 +25,7 @@ use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
+use crate::ActionReuse;
 use crate::Expression;
 use crate::ExpressionAction;
 use crate::ExpressionVisitor;
@@ -34,20 +38,174 @@ pub struct ExpressionChain {
     pub actions: Vec<ExpressionAction>,
 }
 
+/// Default maximum nesting depth of the expressions in a chain.
+pub const DEFAULT_MAX_EXPRESSION_DEPTH: usize = 1024;
+
+/// Default maximum number of actions of an expression chain.
+pub const DEFAULT_MAX_EXPRESSION_ACTIONS: usize = 8192;
+
+/// Bounds the expressions a chain is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpressionLimits {
+    /// Maximum nesting depth of an expression.
+    pub max_depth: usize,
+    /// Maximum number of actions of the chain.
+    pub max_actions: usize,
+}
+
+impl Default for ExpressionLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_EXPRESSION_DEPTH,
+            max_actions: DEFAULT_MAX_EXPRESSION_ACTIONS,
+        }
+    }
+}
+
 impl ExpressionChain {
     pub fn try_create(schema: DataSchemaRef, exprs: &[Expression]) -> Result<Self> {
+        Self::try_create_with_limits(schema, exprs, &ExpressionLimits::default())
+    }
+
+    pub fn try_create_with_limits(
+        schema: DataSchemaRef,
+        exprs: &[Expression],
+        limits: &ExpressionLimits,
+    ) -> Result<Self> {
         let mut chain = Self {
             schema,
             actions: vec![],
         };
 
         for expr in exprs {
+            Self::check_depth(expr, limits.max_depth)?;
             chain.recursion_add_expr(expr)?;
         }
 
+        if chain.actions.len() > limits.max_actions {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expression chain has {} actions, exceeds the maximum {}",
+                chain.actions.len(),
+                limits.max_actions
+            )));
+        }
+
+        chain.validate_order()?;
         Ok(chain)
     }
 
+    /// Check that every function and alias action only depends on input columns
+    /// or on columns produced by an earlier action in the chain.
+    pub fn validate_order(&self) -> Result<()> {
+        let mut produced: HashSet<&str> = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect();
+
+        for action in self.actions.iter() {
+            let arg_names = match action {
+                ExpressionAction::Function(f) => f.arg_names.iter().collect::<Vec<_>>(),
+                ExpressionAction::Alias(a) => vec![&a.arg_name],
+                ExpressionAction::Reuse(r) => vec![&r.arg_name],
+                _ => vec![],
+            };
+
+            for arg_name in arg_names {
+                if !produced.contains(arg_name.as_str()) {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Expression action {} depends on {}, which is not produced before it",
+                        action.column_name(),
+                        arg_name
+                    )));
+                }
+            }
+            produced.insert(action.column_name());
+        }
+        Ok(())
+    }
+
+    // Building actions calls recursive helpers(to_data_type, column_name),
+    // so the depth must be checked before any action is added.
+    fn check_depth(expr: &Expression, max_depth: usize) -> Result<()> {
+        struct ExpressionDepthVisitor {
+            depth: usize,
+            max_depth: usize,
+        }
+
+        impl ExpressionVisitor for ExpressionDepthVisitor {
+            fn pre_visit(mut self, _expr: &Expression) -> Result<Recursion<Self>> {
+                self.depth += 1;
+                if self.depth > self.max_depth {
+                    return Err(ErrorCode::BadArguments("expression too deeply nested"));
+                }
+                Ok(Recursion::Continue(self))
+            }
+
+            fn post_visit(mut self, _expr: &Expression) -> Result<Self> {
+                self.depth -= 1;
+                Ok(self)
+            }
+        }
+
+        ExpressionDepthVisitor {
+            depth: 0,
+            max_depth,
+        }
+        .visit(expr)?;
+        Ok(())
+    }
+
+    // Sort the arguments of commutative functions by column name, so that `a + b` and `b + a`
+    // become the same function action. Only arguments of the same type are reordered, the
+    // function implementations may not be symmetric over mixed types(e.g. date + int).
+    fn canonicalize_args(
+        op: &str,
+        arg_names: &mut [String],
+        arg_types: &[DataTypeAndNullable],
+    ) -> Result<()> {
+        if arg_names.len() < 2 || arg_types.windows(2).any(|w| w[0] != w[1]) {
+            return Ok(());
+        }
+
+        if FunctionFactory::instance().get_features(op)?.is_commutative {
+            arg_names.sort();
+        }
+        Ok(())
+    }
+
+    // An earlier action computing the same deterministic function over the same arguments is
+    // reused instead of evaluating the function again, `rand()` is evaluated every time.
+    fn add_function(&mut self, function: ActionFunction) -> Result<()> {
+        if !function.is_deterministic()? {
+            self.actions.push(ExpressionAction::Function(function));
+            return Ok(());
+        }
+
+        let equivalent = self.actions.iter().find_map(|action| match action {
+            ExpressionAction::Function(f)
+                if f.name != function.name
+                    && f.func_name.eq_ignore_ascii_case(&function.func_name)
+                    && f.arg_names == function.arg_names =>
+            {
+                Some(f.name.clone())
+            }
+            _ => None,
+        });
+
+        match equivalent {
+            Some(arg_name) => self.actions.push(ExpressionAction::Reuse(ActionReuse {
+                name: function.name,
+                arg_name,
+                return_type: function.return_type,
+                is_nullable: function.is_nullable,
+            })),
+            None => self.actions.push(ExpressionAction::Function(function)),
+        }
+        Ok(())
+    }
+
     fn recursion_add_expr(&mut self, expr: &Expression) -> Result<()> {
         struct ExpressionActionVisitor(*mut ExpressionChain);
 
@@ -145,6 +303,8 @@ impl ExpressionChain {
                     left.to_data_type_and_nullable(&self.schema)?,
                     right.to_data_type_and_nullable(&self.schema)?,
                 ];
+                let mut arg_names = vec![left.column_name(), right.column_name()];
+                Self::canonicalize_args(op, &mut arg_names, &arg_types)?;
 
                 let func = FunctionFactory::instance().get(op, &arg_types)?;
                 let return_type = func.return_type(&arg_types)?;
@@ -153,13 +313,13 @@ impl ExpressionChain {
                     name: expr.column_name(),
                     func_name: op.clone(),
                     func,
-                    arg_names: vec![left.column_name(), right.column_name()],
+                    arg_names,
                     arg_types,
                     is_nullable: return_type.is_nullable(),
                     return_type: return_type.data_type().clone(),
                 };
 
-                self.actions.push(ExpressionAction::Function(function));
+                self.add_function(function)?;
             }
 
             Expression::ScalarFunction { op, args } => {
@@ -167,6 +327,9 @@ impl ExpressionChain {
                     .iter()
                     .map(|action| action.to_data_type_and_nullable(&self.schema))
                     .collect::<Result<Vec<_>>>()?;
+                let mut arg_names: Vec<String> =
+                    args.iter().map(|action| action.column_name()).collect();
+                Self::canonicalize_args(op, &mut arg_names, &arg_types)?;
 
                 let func = FunctionFactory::instance().get(op, &arg_types)?;
                 let return_type = func.return_type(&arg_types)?;
@@ -175,13 +338,13 @@ impl ExpressionChain {
                     name: expr.column_name(),
                     func_name: op.clone(),
                     func,
-                    arg_names: args.iter().map(|action| action.column_name()).collect(),
+                    arg_names,
                     arg_types,
                     is_nullable: return_type.is_nullable(),
                     return_type: return_type.data_type().clone(),
                 };
 
-                self.actions.push(ExpressionAction::Function(function));
+                self.add_function(function)?;
             }
 
             Expression::AggregateFunction { .. } => {
@@ -0,0 +1,153 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+
+#[test]
+fn test_expression_chain_max_depth() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    // ((a + 1) + 1) + 1 ..., nested 10 times, depth is 11
+    let mut expr = col("a");
+    for _ in 0..10 {
+        expr = add(expr, lit(1i64));
+    }
+
+    let limits = ExpressionLimits {
+        max_depth: 11,
+        ..Default::default()
+    };
+    let chain = ExpressionChain::try_create_with_limits(schema.clone(), &[expr.clone()], &limits)?;
+    assert!(!chain.actions.is_empty());
+
+    let limits = ExpressionLimits {
+        max_depth: 10,
+        ..Default::default()
+    };
+    let result = ExpressionChain::try_create_with_limits(schema.clone(), &[expr], &limits);
+    let error = result.unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_arguments_code());
+    assert_eq!(error.message(), "expression too deeply nested");
+
+    // Past the default limit, depth is DEFAULT_MAX_EXPRESSION_DEPTH + 1
+    let mut expr = col("a");
+    for _ in 0..DEFAULT_MAX_EXPRESSION_DEPTH {
+        expr = add(expr, lit(1i64));
+    }
+    let result = ExpressionChain::try_create(schema, &[expr]);
+    assert_eq!(result.unwrap_err().code(), ErrorCode::bad_arguments_code());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_chain_validate_order() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    let chain = ExpressionChain::try_create(schema.clone(), &[add(col("a"), lit(1i64))])?;
+    chain.validate_order()?;
+
+    // Move the function in front of the constant it depends on.
+    let mut actions = chain.actions.clone();
+    let function = actions.pop().unwrap();
+    actions.insert(0, function);
+
+    let chain = ExpressionChain { schema, actions };
+    let error = chain.validate_order().unwrap_err();
+    assert_eq!(error.code(), ErrorCode::logical_error_code());
+    assert_eq!(
+        error.message(),
+        "Expression action (a + 1) depends on 1, which is not produced before it"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_chain_commutative_reuse() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+
+    let exprs = vec![
+        add(col("a"), col("b")),
+        add(col("b"), col("a")),
+        sub(col("b"), col("a")),
+    ];
+    let chain = ExpressionChain::try_create(schema, &exprs)?;
+
+    let functions = chain
+        .actions
+        .iter()
+        .filter_map(|action| match action {
+            ExpressionAction::Function(f) => Some((f.name.as_str(), f.arg_names.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(functions, vec![
+        ("(a + b)", vec!["a".to_string(), "b".to_string()]),
+        ("(b - a)", vec!["b".to_string(), "a".to_string()]),
+    ]);
+
+    let reuses = chain
+        .actions
+        .iter()
+        .filter_map(|action| match action {
+            ExpressionAction::Reuse(r) => Some((r.name.as_str(), r.arg_name.as_str())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(reuses, vec![("(b + a)", "(a + b)")]);
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_chain_non_deterministic_no_reuse() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    let exprs = vec![
+        Expression::create_scalar_function("abs", vec![col("a")]),
+        Expression::create_scalar_function("ABS", vec![col("a")]),
+        Expression::create_scalar_function("rand", vec![col("a")]),
+        Expression::create_scalar_function("RAND", vec![col("a")]),
+    ];
+    let chain = ExpressionChain::try_create(schema, &exprs)?;
+
+    let functions = chain
+        .actions
+        .iter()
+        .filter_map(|action| match action {
+            ExpressionAction::Function(f) => Some(f.name.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(functions, vec!["abs(a)", "rand(a)", "RAND(a)"]);
+
+    let reuses = chain
+        .actions
+        .iter()
+        .filter_map(|action| match action {
+            ExpressionAction::Reuse(r) => Some((r.name.as_str(), r.arg_name.as_str())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(reuses, vec![("ABS(a)", "abs(a)")]);
+
+    Ok(())
+}
@@ -31,6 +31,8 @@ pub struct CsvSource<R> {
     schema: DataSchemaRef,
     block_size: usize,
     rows: usize,
+    /// The texts read as null for the nullable fields.
+    null_tokens: Vec<Vec<u8>>,
 }
 
 impl<R> CsvSource<R>
@@ -61,8 +63,15 @@ where R: AsyncRead + Unpin + Send
             block_size,
             schema,
             rows: 0,
+            null_tokens: vec![b"\\N".to_vec()],
         })
     }
+
+    #[must_use]
+    pub fn with_null_tokens(mut self, null_tokens: Vec<Vec<u8>>) -> Self {
+        self.null_tokens = null_tokens;
+        self
+    }
 }
 
 #[async_trait]
@@ -76,6 +85,14 @@ where R: AsyncRead + Unpin + Send
             .iter()
             .map(|f| f.data_type().create_deserializer(self.block_size))
             .collect::<Result<Vec<_>>>()?;
+        let nullables = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.is_nullable())
+            .collect::<Vec<_>>();
+        let null_tokens = &self.null_tokens;
+        let is_null_token = |bytes: &[u8]| null_tokens.iter().any(|t| t.as_slice() == bytes);
 
         let mut rows = 0;
         let mut records = self.reader.byte_records();
@@ -90,6 +107,7 @@ where R: AsyncRead + Unpin + Send
             }
             for (col, deser) in desers.iter_mut().enumerate() {
                 match record.get(col) {
+                    Some(bytes) if nullables[col] && is_null_token(bytes) => deser.de_null(),
                     Some(bytes) => deser.de_text(bytes)?,
                     None => deser.de_null(),
                 }
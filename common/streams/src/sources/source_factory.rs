@@ -68,14 +68,23 @@ impl SourceFactory {
                     })
                     .unwrap_or(b'\n');
 
-                Ok(Box::new(CsvSource::try_create(
+                let source = CsvSource::try_create(
                     params.reader,
                     params.schema,
                     has_header.eq_ignore_ascii_case("1"),
                     field_delimitor,
                     record_delimitor,
                     params.max_block_size,
-                )?))
+                )?;
+
+                // Separated by comma, e.g. `\N,NULL,` reads `\N`, `NULL` and empty as null.
+                match params.options.get("null_tokens") {
+                    Some(v) => {
+                        let null_tokens = v.split(',').map(|t| t.as_bytes().to_vec()).collect();
+                        Ok(Box::new(source.with_null_tokens(null_tokens)))
+                    }
+                    None => Ok(Box::new(source)),
+                }
             }
             "parquet" => Ok(Box::new(ParquetSource::new(
                 params.reader,
@@ -120,6 +120,53 @@ async fn test_parse_csvs() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_csv_null_tokens() {
+    let dir = tempfile::tempdir().unwrap();
+    let name = "my-temporary-note.txt";
+    let file_path = dir.path().join(name);
+    let mut file = File::create(file_path).unwrap();
+    write!(file, "1,a\n\\N,\\N\n,\nNULL,NULL\n").unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::String, true),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    // Only \N is read as null by default, and only for the nullable fields.
+    let local = Local::with_path(dir.path().to_path_buf());
+    let stream = local.get_input_stream(name, None).unwrap();
+    let mut csv_source =
+        CsvSource::try_create(stream, schema.clone(), false, b',', b'\n', 10).unwrap();
+    let block = csv_source.read().await.unwrap().unwrap();
+    assert_blocks_eq(
+        vec![
+            "+------+------+",
+            "| a    | b    |",
+            "+------+------+",
+            "| 1    | a    |",
+            "| NULL | \\N   |",
+            "|      |      |",
+            "| NULL | NULL |",
+            "+------+------+",
+        ],
+        &[block.clone()],
+    );
+    assert_eq!(block.column(0).to_array().unwrap().null_count(), 1);
+
+    // The configured tokens are read as null.
+    let stream = local.get_input_stream(name, None).unwrap();
+    let mut csv_source = CsvSource::try_create(stream, schema, false, b',', b'\n', 10)
+        .unwrap()
+        .with_null_tokens(vec![b"\\N".to_vec(), b"".to_vec(), b"NULL".to_vec()]);
+    let block = csv_source.read().await.unwrap().unwrap();
+    assert_eq!(block.column(0).to_array().unwrap().null_count(), 3);
+    assert_eq!(block.column(1).to_array().unwrap().null_count(), 0);
+
+    drop(file);
+    dir.close().unwrap();
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_source_parquet() -> Result<()> {
     use common_datavalues::DataType;
@@ -85,3 +85,39 @@ async fn test_skipstream() {
         index += 1;
     }
 }
+
+#[tokio::test]
+async fn test_skipstream_with_null_column() {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::Int32, false),
+        DataField::new("n", DataType::Null, true),
+    ]);
+
+    let block0 = DataBlock::create(schema.clone(), vec![
+        Series::new((0..20).collect::<Vec<i32>>()).into(),
+        DataColumn::Constant(DataValue::Null, 20),
+    ]);
+    let block1 = DataBlock::create(schema.clone(), vec![
+        Series::new((20..40).collect::<Vec<i32>>()).into(),
+        DataColumn::Constant(DataValue::Null, 20),
+    ]);
+
+    let stream = DataBlockStream::create(schema, None, vec![block0, block1]);
+
+    // LIMIT 10 OFFSET 15
+    let skip_stream = SkipStream::new(Box::pin(stream), 15);
+    let mut take_stream = TakeStream::new(Box::pin(skip_stream), 10);
+
+    let mut lengths = vec![];
+    while let Some(res) = take_stream.next().await {
+        let block = res.unwrap();
+        let null_column = block.column(1);
+        assert!(matches!(
+            null_column,
+            DataColumn::Constant(DataValue::Null, _)
+        ));
+        assert_eq!(null_column.len(), block.num_rows());
+        lengths.push(block.num_rows());
+    }
+    assert_eq!(lengths, vec![5, 5]);
+}
@@ -15,18 +15,25 @@
 use std::sync::Arc;
 
 use common_arrow::arrow_format::flight::data::BasicAuth;
+use common_base::tokio;
+use common_base::tokio::sync::mpsc;
+use common_exception::SerializedError;
 use common_grpc::GrpcClaim;
 use common_grpc::GrpcToken;
 use common_meta_grpc::MetaGrpcReadReq;
 use common_meta_grpc::MetaGrpcWriteReq;
+use common_meta_grpc::PrefixListReq;
 use common_meta_types::protobuf::meta_service_server::MetaService;
 use common_meta_types::protobuf::HandshakeRequest;
 use common_meta_types::protobuf::HandshakeResponse;
+use common_meta_types::protobuf::PingRequest;
+use common_meta_types::protobuf::PingResponse;
 use common_meta_types::protobuf::RaftReply;
 use common_meta_types::protobuf::RaftRequest;
 use common_tracing::tracing;
 use futures::StreamExt;
 use prost::Message;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::metadata::MetadataMap;
 use tonic::Request;
 use tonic::Response;
@@ -37,6 +44,9 @@ use crate::executor::ActionHandler;
 use crate::meta_service::meta_service_impl::GrpcStream;
 use crate::meta_service::MetaNode;
 
+/// How many kv items are replied in a page of `list_kv`.
+pub const LIST_KV_PAGE_SIZE: usize = 1000;
+
 pub struct MetaServiceImpl {
     token: GrpcToken,
     action_handler: ActionHandler,
@@ -70,6 +80,8 @@ impl MetaService for MetaServiceImpl {
     // rpc handshake related type
     type HandshakeStream = GrpcStream<HandshakeResponse>;
 
+    type ListKVStream = GrpcStream<RaftReply>;
+
     // rpc handshake first
     #[tracing::instrument(level = "info", skip(self))]
     async fn handshake(
@@ -130,11 +142,61 @@ impl MetaService for MetaServiceImpl {
         let action: MetaGrpcReadReq = request.try_into()?;
         tracing::info!("Receive read_action: {:?}", action);
 
-        let body = self.action_handler.execute_read(action).await?;
-        let r = RaftReply {
-            data: body,
-            error: "".to_string(),
+        // An error of the action is replied in the envelope as a `SerializedError` as a write does,
+        // thus a grpc status means only a service failure, e.g., the meta is unavailable.
+        let r = match self.action_handler.execute_read(action).await {
+            Ok(data) => RaftReply {
+                data,
+                error: "".to_string(),
+            },
+            Err(e) => RaftReply::from(Err::<String, _>(SerializedError::from(e))),
         };
         Ok(Response::new(r))
     }
+
+    async fn list_kv(
+        &self,
+        request: Request<RaftRequest>,
+    ) -> Result<Response<Self::ListKVStream>, Status> {
+        self.check_token(request.metadata())?;
+        common_tracing::extract_remote_span_as_parent(&request);
+
+        let req: PrefixListReq = serde_json::from_str(&request.into_inner().data)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        tracing::info!("Receive list_kv: {:?}", req);
+
+        // Each page is read from the state machine when the previous one is sent,
+        // the prefix is never loaded into memory at once.
+        let meta_node = self.action_handler.meta_node.clone();
+        let (tx, rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            let mut start_after = None;
+            loop {
+                let page = meta_node
+                    .prefix_list_kv_page(&req.0, start_after.take(), LIST_KV_PAGE_SIZE)
+                    .await;
+                let page = match page {
+                    Ok(page) => page,
+                    Err(e) => {
+                        let reply = RaftReply::from(Err::<String, _>(SerializedError::from(e)));
+                        let _ = tx.send(Ok(reply)).await;
+                        return;
+                    }
+                };
+
+                let last = page.len() < LIST_KV_PAGE_SIZE;
+                start_after = page.last().map(|(k, _)| k.clone());
+                let reply = RaftReply::from(Ok::<_, SerializedError>(page));
+                if tx.send(Ok(reply)).await.is_err() || last {
+                    // The client is gone or all the pages are sent.
+                    return;
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+
+    async fn ping(&self, _request: Request<PingRequest>) -> Result<Response<PingResponse>, Status> {
+        Ok(Response::new(PingResponse {}))
+    }
 }
@@ -60,6 +60,14 @@ impl ActionHandler {
                     .map_err(SerializedError::from);
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::CasKV(a) => {
+                let r = self
+                    .meta_node
+                    .cas_kv(a)
+                    .await
+                    .map_err(SerializedError::from);
+                RaftReply::from(r)
+            }
             // database
             MetaGrpcWriteReq::CreateDatabase(a) => {
                 let r = self.handle(a).await.map_err(SerializedError::from);
@@ -98,7 +98,12 @@ impl<'a> MetaLeader<'a> {
             }
             ForwardRequestBody::ListKV(req) => {
                 let sm = self.meta_node.get_state_machine().await;
-                let res = sm.prefix_list_kv(&req.prefix).await?;
+                let res = match req.limit {
+                    Some(limit) => {
+                        sm.prefix_list_kv_page(&req.prefix, req.start_after.as_deref(), limit)?
+                    }
+                    None => sm.prefix_list_kv(&req.prefix).await?,
+                };
                 Ok(ForwardResponse::ListKV(res))
             }
         }
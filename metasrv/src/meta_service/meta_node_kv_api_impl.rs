@@ -16,6 +16,8 @@ use async_trait::async_trait;
 use common_exception::ErrorCode;
 use common_meta_api::KVApi;
 use common_meta_types::AppliedState;
+use common_meta_types::CasKVAction;
+use common_meta_types::CasKVReply;
 use common_meta_types::Cmd;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetKVReq;
@@ -61,6 +63,26 @@ impl KVApi for MetaNode {
         }
     }
 
+    async fn cas_kv(&self, act: CasKVAction) -> common_exception::Result<CasKVReply> {
+        let ent = LogEntry {
+            txid: None,
+            cmd: Cmd::CasKV {
+                key: act.key,
+                expected: act.expected.clone(),
+                value: act.value,
+            },
+        };
+        let rst = self
+            .write(ent)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        match rst {
+            AppliedState::KV(x) => Ok(CasKVReply::from_change(&act.expected, x)),
+            _ => Err(ErrorCode::MetaNodeInternalError("not a KV result")),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn get_kv(&self, key: &str) -> common_exception::Result<GetKVActionReply> {
         let res = self
@@ -88,6 +110,30 @@ impl KVApi for MetaNode {
         let res = self
             .consistent_read(ListKVReq {
                 prefix: prefix.to_string(),
+                start_after: None,
+                limit: None,
+            })
+            .await?;
+
+        Ok(res)
+    }
+}
+
+impl MetaNode {
+    /// List a page of at most `limit` kv of a prefix, from the key after `start_after`.
+    /// A page with less than `limit` items is the last page.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> common_exception::Result<PrefixListReply> {
+        let res = self
+            .consistent_read(ListKVReq {
+                prefix: prefix.to_string(),
+                start_after,
+                limit: Some(limit),
             })
             .await?;
 
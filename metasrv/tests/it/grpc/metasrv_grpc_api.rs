@@ -16,16 +16,28 @@
 
 use common_base::tokio;
 use common_base::Stoppable;
+use common_exception::ErrorCode;
+use common_exception::SerializedError;
 use common_meta_api::KVApi;
+use common_meta_api::MetaApi;
 use common_meta_grpc::MetaGrpcClient;
+use common_meta_grpc::MetaGrpcReadReq;
+use common_meta_types::protobuf::meta_service_client::MetaServiceClient;
+use common_meta_types::protobuf::RaftRequest;
+use common_meta_types::GetDatabaseReq;
 use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_tracing::tracing;
+use databend_meta::api::grpc::grpc_service::LIST_KV_PAGE_SIZE;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 use tokio::time::Duration;
+use tonic::metadata::MetadataValue;
+use tonic::Code;
+use tonic::Request;
 
 use crate::init_meta_ut;
 use crate::tests::service::MetaSrvTestContext;
@@ -203,3 +215,118 @@ async fn test_join() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_auth_token() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Requests with the token from handshake are served.
+    // - Requests without token or with a wrong token are rejected.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    tracing::info!("--- valid token");
+    {
+        let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+        let res = client.get_kv("foo").await?;
+        assert_eq!(None, res);
+    }
+
+    tracing::info!("--- missing token");
+    {
+        let mut client = MetaServiceClient::connect(format!("http://{}", addr)).await?;
+        let status = client.read_msg(RaftRequest::default()).await.unwrap_err();
+        assert_eq!(Code::Unauthenticated, status.code());
+
+        let err = ErrorCode::from(status);
+        assert_eq!(ErrorCode::authenticate_failure_code(), err.code());
+    }
+
+    tracing::info!("--- wrong token");
+    {
+        let mut client = MetaServiceClient::connect(format!("http://{}", addr)).await?;
+        let mut req = Request::new(RaftRequest::default());
+        req.metadata_mut()
+            .insert_bin("auth-token-bin", MetadataValue::from_bytes(b"wrong"));
+        let status = client.read_msg(req).await.unwrap_err();
+        assert_eq!(Code::Unauthenticated, status.code());
+
+        let err = ErrorCode::from(status);
+        assert_eq!(ErrorCode::authenticate_failure_code(), err.code());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_list_kv_stream() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Write more keys than a page under a prefix, and one key out of it.
+    // - The stream yields all the keys of the prefix in order, across pages.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+    let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+
+    let n = LIST_KV_PAGE_SIZE + 1;
+    let keys = (0..n).map(|i| format!("list/{:06}", i)).collect::<Vec<_>>();
+    for key in keys.iter().chain(["other".to_string()].iter()) {
+        client
+            .upsert_kv(UpsertKVAction::new(
+                key,
+                MatchSeq::Any,
+                Operation::Update(key.clone().into_bytes()),
+                None,
+            ))
+            .await?;
+    }
+
+    let items = client
+        .list_kv_stream("list/")
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let listed = items.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+    assert_eq!(keys, listed);
+    assert_eq!(keys[n - 1].clone().into_bytes(), items[n - 1].1.data);
+
+    let items = client.prefix_list_kv("list/").await?;
+    assert_eq!(n, items.len());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_read_error_in_reply() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - A failed read is replied with the error in the envelope, not as a grpc status.
+    // - The client rebuilds the original ErrorCode from it.
+
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+    let client = MetaGrpcClient::try_create(addr.as_str(), "root", "xxx", None, None).await?;
+
+    let act = MetaGrpcReadReq::GetDatabase(GetDatabaseReq::new("tenant1", "absent"));
+    let req: Request<RaftRequest> = (&act).try_into()?;
+    let mut raw_client = client.make_client().await?;
+    let reply = raw_client.read_msg(req).await?.into_inner();
+    assert_eq!("", reply.data);
+
+    let err: SerializedError = serde_json::from_str(&reply.error)?;
+    let err = ErrorCode::from(err);
+    assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+    let err = client
+        .get_database(GetDatabaseReq::new("tenant1", "absent"))
+        .await
+        .unwrap_err();
+    assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+    Ok(())
+}
@@ -16,7 +16,6 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_meta_types::NodeInfo;
-use poem::http::StatusCode;
 use poem::web::Data;
 use poem::web::IntoResponse;
 use poem::web::Json;
@@ -35,7 +34,7 @@ pub async fn cluster_list_handler(
     let nodes = list_nodes(sessions.0).await.map_err(|cause| {
         poem::Error::from_string(
             format!("Failed to fetch cluster nodes list. cause: {}", cause),
-            StatusCode::INTERNAL_SERVER_ERROR,
+            cause.to_http_status(),
         )
     })?;
     Ok(Json(nodes))
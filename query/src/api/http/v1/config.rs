@@ -12,11 +12,134 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
+use poem::error::InternalServerError;
+use poem::http::header;
+use poem::http::HeaderMap;
+use poem::http::StatusCode;
 use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+use poem::web::Query;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
 
 use crate::configs::Config;
+use crate::interpreters::management_mode_allowed_statements;
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AccessPolicy {
+    pub management_mode: bool,
+    // Statements allowed when management_mode is on, by plan name.
+    pub allowed_statements: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ConfigRequest {
+    // json(default), toml or text
+    pub format: Option<String>,
+}
+
+// GET /v1/config?format=json|toml|text
+// request: without `format`, it is negotiated by the `Accept` header:
+// application/json(default), application/toml or text/plain, other types are rejected with 406
+// return: the config with the secrets masked, in the requested format,
+// text is a `section.key=value` line per field
+#[poem::handler]
+pub async fn config_handler(
+    cfg: Data<&Config>,
+    req: Option<Query<ConfigRequest>>,
+    headers: &HeaderMap,
+) -> poem::Result<impl IntoResponse> {
+    let config = cfg.0.redacted();
+    let format = match req.and_then(|query| query.0.format) {
+        Some(format) => format,
+        None => negotiate_format(headers)?.to_string(),
+    };
+
+    let (content_type, body) = match format.to_lowercase().as_str() {
+        "json" => (
+            "application/json",
+            serde_json::to_string(&config).map_err(InternalServerError)?,
+        ),
+        "toml" => (
+            "application/toml",
+            toml::to_string(&config).map_err(InternalServerError)?,
+        ),
+        "text" => (
+            "text/plain",
+            to_key_values(&config).map_err(InternalServerError)?,
+        ),
+        other => {
+            let err = ErrorCode::BadArguments(format!(
+                "Unsupported config format: {}, expect json, toml or text",
+                other
+            ));
+            return Err(poem::Error::from_string(
+                err.message(),
+                err.to_http_status(),
+            ));
+        }
+    };
+
+    Ok(body.with_content_type(content_type))
+}
+
+// The format of the first supported media type in `Accept`, json if it is absent.
+fn negotiate_format(headers: &HeaderMap) -> poem::Result<&'static str> {
+    let accept = match headers.get(header::ACCEPT) {
+        None => return Ok("json"),
+        Some(accept) => accept.to_str().unwrap_or_default(),
+    };
+
+    for media_type in accept.split(',') {
+        let media_type = media_type.split(';').next().unwrap_or_default().trim();
+        match media_type.to_lowercase().as_str() {
+            "application/json" | "application/*" | "*/*" => return Ok("json"),
+            "application/toml" => return Ok("toml"),
+            "text/plain" | "text/*" => return Ok("text"),
+            _ => continue,
+        }
+    }
+
+    Err(poem::Error::from_string(
+        format!(
+            "Unsupported Accept: {}, expect application/json, application/toml or text/plain",
+            accept
+        ),
+        StatusCode::NOT_ACCEPTABLE,
+    ))
+}
+
+fn to_key_values(config: &Config) -> serde_json::Result<String> {
+    fn flatten(key: &str, value: &Value, lines: &mut Vec<String>) {
+        match value {
+            Value::Object(fields) => {
+                for (name, field) in fields {
+                    let key = match key {
+                        "" => name.clone(),
+                        _ => format!("{}.{}", key, name),
+                    };
+                    flatten(&key, field, lines);
+                }
+            }
+            Value::String(s) => lines.push(format!("{}={}", key, s)),
+            other => lines.push(format!("{}={}", key, other)),
+        }
+    }
+
+    let mut lines = vec![];
+    flatten("", &serde_json::to_value(config)?, &mut lines);
+    Ok(lines.join("\n"))
+}
+
+// GET /v1/config/access_policy
+// return: the management-mode flag and the statements allowed in management-mode
 #[poem::handler]
-pub async fn config_handler(cfg: Data<&Config>) -> String {
-    format!("{:?}", cfg.0)
+pub async fn access_policy_handler(cfg: Data<&Config>) -> Json<AccessPolicy> {
+    Json(AccessPolicy {
+        management_mode: cfg.0.query.management_mode,
+        allowed_statements: management_mode_allowed_statements(cfg.0),
+    })
 }
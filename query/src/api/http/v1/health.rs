@@ -12,8 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_tracing::tracing;
+use poem::http::StatusCode;
+use poem::web::Data;
 use poem::web::Json;
 use poem::IntoResponse;
+use poem::Response;
+
+use crate::common::MetaClientProvider;
+use crate::sessions::SessionManager;
 
 #[derive(serde::Serialize)]
 pub struct HealthCheckResponse {
@@ -24,11 +34,43 @@ pub struct HealthCheckResponse {
 #[serde(rename_all = "camelCase")]
 pub enum HealthCheckStatus {
     Pass,
+    Fail,
 }
 
+// GET /v1/health
+// readiness of the node, distinct from the process being up
+// request: None
+// return: 200 with status `pass` if a session can be served and the meta service is reachable,
+// 503 with status `fail` otherwise
 #[poem::handler]
-pub async fn health_handler() -> impl IntoResponse {
-    Json(HealthCheckResponse {
-        status: HealthCheckStatus::Pass,
-    })
+pub async fn health_handler(sessions: Data<&Arc<SessionManager>>) -> Response {
+    match check_health(sessions.0).await {
+        Ok(_) => Json(HealthCheckResponse {
+            status: HealthCheckStatus::Pass,
+        })
+        .into_response(),
+        Err(cause) => {
+            tracing::warn!("Health check failed, cause: {}", cause);
+            Json(HealthCheckResponse {
+                status: HealthCheckStatus::Fail,
+            })
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+            .into_response()
+        }
+    }
+}
+
+async fn check_health(sessions: &Arc<SessionManager>) -> Result<()> {
+    let health_check_session = sessions.create_session("HealthCheck")?;
+    health_check_session.create_context().await?;
+
+    // The embedded meta store is always reachable.
+    let meta_conf = sessions.get_conf().meta.to_grpc_client_config();
+    if !meta_conf.meta_service_config.address.is_empty() {
+        let meta_client = MetaClientProvider::new(meta_conf)
+            .try_get_meta_client()
+            .await?;
+        meta_client.ping().await?;
+    }
+    Ok(())
 }
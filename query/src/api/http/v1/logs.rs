@@ -17,7 +17,6 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_streams::SendableDataBlockStream;
-use poem::http::StatusCode;
 use poem::web::Data;
 use poem::Body;
 use poem::IntoResponse;
@@ -35,7 +34,7 @@ pub async fn logs_handler(
     let data = select_table(sessions_extension.0).await.map_err(|err| {
         poem::Error::from_string(
             format!("Failed to fetch log. Error: {}", err),
-            StatusCode::INTERNAL_SERVER_ERROR,
+            err.to_http_status(),
         )
     })?;
     Ok(data)
@@ -15,6 +15,7 @@
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::Result;
 use common_tracing::tracing;
@@ -24,7 +25,10 @@ use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
 
+use crate::common::service::HttpCompression;
+use crate::common::service::HttpMetrics;
 use crate::common::service::HttpShutdownHandler;
+use crate::common::service::HttpTimeout;
 use crate::configs::Config;
 use crate::servers::Server;
 use crate::sessions::SessionManager;
@@ -43,9 +47,20 @@ impl HttpService {
     }
 
     fn build_router(&self) -> impl Endpoint {
+        let config_timeout =
+            Duration::from_millis(self.sessions.get_conf().query.http_config_timeout_ms);
+
         Route::new()
             .at("/v1/health", get(super::http::v1::health::health_handler))
-            .at("/v1/config", get(super::http::v1::config::config_handler))
+            .at(
+                "/v1/config",
+                get(super::http::v1::config::config_handler)
+                    .with(HttpTimeout::create(config_timeout)),
+            )
+            .at(
+                "/v1/config/access_policy",
+                get(super::http::v1::config::access_policy_handler),
+            )
             .at("/v1/logs", get(super::http::v1::logs::logs_handler))
             .at(
                 "/v1/cluster/list",
@@ -61,6 +76,10 @@ impl HttpService {
             )
             .data(self.sessions.clone())
             .data(self.sessions.get_conf().clone())
+            .with(HttpCompression::create(
+                self.sessions.get_conf().query.http_compression_min_size as usize,
+            ))
+            .with(HttpMetrics::create("http_api"))
     }
 
     fn build_tls(config: &Config) -> Result<RustlsConfig> {
@@ -114,9 +114,12 @@ impl FlightService for DatabendQueryFlightService {
 
                 serialize_schema(&arrow_schema, &ipc_fields);
 
+                let max_rows_per_batch =
+                    self.sessions.get_conf().query.flight_max_rows_per_batch as usize;
+
+                let stream = FlightDataStream::create(receiver, ipc_fields, max_rows_per_batch);
                 Ok(RawResponse::new(
-                    Box::pin(FlightDataStream::create(receiver, ipc_fields))
-                        as FlightStream<FlightData>,
+                    Box::pin(stream) as FlightStream<FlightData>
                 ))
             }
         }
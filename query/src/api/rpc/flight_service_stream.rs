@@ -12,16 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::convert::TryInto;
 
 use common_arrow::arrow::io::flight::serialize_batch;
 use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::arrow::io::ipc::IpcField;
+use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::arrow_format::flight::data::FlightData;
 use common_base::tokio::macros::support::Pin;
 use common_base::tokio::macros::support::Poll;
 use common_base::tokio::sync::mpsc::Receiver;
 use common_datablocks::DataBlock;
+use futures::ready;
 use futures::task::Context;
 use tokio_stream::Stream;
 use tonic::Status;
@@ -30,17 +33,49 @@ pub struct FlightDataStream {
     input: Receiver<common_exception::Result<DataBlock>>,
     ipc_fields: Vec<IpcField>,
     options: WriteOptions,
+    max_rows_per_batch: usize,
+    // Slices of the received block which are not sent yet.
+    pending: VecDeque<DataBlock>,
 }
 
 impl FlightDataStream {
     pub fn create(
         input: Receiver<common_exception::Result<DataBlock>>,
         ipc_fields: Vec<IpcField>,
+        max_rows_per_batch: usize,
     ) -> FlightDataStream {
         FlightDataStream {
             input,
             ipc_fields,
             options: WriteOptions { compression: None },
+            max_rows_per_batch,
+            pending: VecDeque::new(),
+        }
+    }
+
+    // Zero max_rows_per_batch means no limit.
+    fn split_block(&mut self, block: DataBlock) {
+        let rows = block.num_rows();
+        if self.max_rows_per_batch == 0 || rows <= self.max_rows_per_batch {
+            self.pending.push_back(block);
+            return;
+        }
+
+        for offset in (0..rows).step_by(self.max_rows_per_batch) {
+            let length = self.max_rows_per_batch.min(rows - offset);
+            self.pending.push_back(block.slice(offset, length));
+        }
+    }
+
+    fn serialize_block(&self, block: DataBlock) -> Result<FlightData, Status> {
+        let record_batch: RecordBatch = block.try_into().map_err(Status::from)?;
+        let (dicts, values) = serialize_batch(&record_batch, &self.ipc_fields, &self.options);
+
+        match dicts.is_empty() {
+            true => Ok(values),
+            false => Err(Status::unimplemented(
+                "DatabendQuery does not implement dicts.",
+            )),
         }
     }
 }
@@ -49,23 +84,16 @@ impl Stream for FlightDataStream {
     type Item = Result<FlightData, Status>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.input.poll_recv(cx).map(|x| match x {
-            None => None,
-            Some(Err(error)) => Some(Err(Status::from(error))),
-            Some(Ok(block)) => match block.try_into() {
-                Err(error) => Some(Err(Status::from(error))),
-                Ok(record_batch) => {
-                    let (dicts, values) =
-                        serialize_batch(&record_batch, &self.ipc_fields, &self.options);
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Poll::Ready(Some(self.serialize_block(block)));
+            }
 
-                    match dicts.is_empty() {
-                        true => Some(Ok(values)),
-                        false => Some(Err(Status::unimplemented(
-                            "DatabendQuery does not implement dicts.",
-                        ))),
-                    }
-                }
-            },
-        })
+            match ready!(self.input.poll_recv(cx)) {
+                None => return Poll::Ready(None),
+                Some(Err(error)) => return Poll::Ready(Some(Err(Status::from(error)))),
+                Some(Ok(block)) => self.split_block(block),
+            }
+        }
     }
 }
@@ -19,6 +19,7 @@ pub use flight_actions::ShuffleAction;
 pub use flight_client::FlightClient;
 pub use flight_dispatcher::DatabendQueryFlightDispatcher;
 pub use flight_service::DatabendQueryFlightService;
+pub use flight_service_stream::FlightDataStream;
 pub use flight_tickets::FlightTicket;
 pub use flight_tickets::StreamTicket;
 
@@ -78,10 +78,12 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
 
     // MySQL handler.
     {
-        let hostname = conf.query.mysql_handler_host.clone();
-        let listening = format!("{}:{}", hostname, conf.query.mysql_handler_port);
+        let listening = Config::parse_listen_host_port(
+            &conf.query.mysql_handler_host,
+            conf.query.mysql_handler_port,
+        )?;
         let mut handler = MySQLHandler::create(session_manager.clone());
-        let listening = handler.start(listening.parse()?).await?;
+        let listening = handler.start(listening).await?;
         shutdown_handle.add_service(handler);
 
         tracing::info!(
@@ -94,11 +96,13 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
 
     // ClickHouse handler.
     {
-        let hostname = conf.query.clickhouse_handler_host.clone();
-        let listening = format!("{}:{}", hostname, conf.query.clickhouse_handler_port);
+        let listening = Config::parse_listen_host_port(
+            &conf.query.clickhouse_handler_host,
+            conf.query.clickhouse_handler_port,
+        )?;
 
         let mut srv = ClickHouseHandler::create(session_manager.clone());
-        let listening = srv.start(listening.parse()?).await?;
+        let listening = srv.start(listening).await?;
         shutdown_handle.add_service(srv);
 
         tracing::info!(
@@ -110,11 +114,13 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     }
     // HTTP handler.
     {
-        let hostname = conf.query.http_handler_host.clone();
-        let listening = format!("{}:{}", hostname, conf.query.http_handler_port);
+        let listening = Config::parse_listen_host_port(
+            &conf.query.http_handler_host,
+            conf.query.http_handler_port,
+        )?;
 
         let mut srv = HttpHandler::create(session_manager.clone());
-        let listening = srv.start(listening.parse()?).await?;
+        let listening = srv.start(listening).await?;
         shutdown_handle.add_service(srv);
 
         let http_handler_usage = HttpHandler::usage(listening);
@@ -129,7 +135,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     {
         let address = conf.query.metric_api_address.clone();
         let mut srv = MetricService::create(session_manager.clone());
-        let listening = srv.start(address.parse()?).await?;
+        let listening = srv.start(Config::parse_listen_address(&address)?).await?;
         shutdown_handle.add_service(srv);
         tracing::info!("Metric API server listening on {}/metrics", listening);
     }
@@ -138,7 +144,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     {
         let address = conf.query.http_api_address.clone();
         let mut srv = HttpService::create(session_manager.clone());
-        let listening = srv.start(address.parse()?).await?;
+        let listening = srv.start(Config::parse_listen_address(&address)?).await?;
         shutdown_handle.add_service(srv);
         tracing::info!("HTTP API server listening on {}", listening);
     }
@@ -147,7 +153,7 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
     {
         let address = conf.query.flight_api_address.clone();
         let mut srv = RpcService::create(session_manager.clone());
-        let listening = srv.start(address.parse()?).await?;
+        let listening = srv.start(Config::parse_listen_address(&address)?).await?;
         shutdown_handle.add_service(srv);
         tracing::info!("RPC API server listening on {}", listening);
     }
@@ -16,15 +16,21 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_api::ChecksumKVApi;
 use common_meta_api::KVApi;
 use common_meta_grpc::MetaGrpcClient;
 use common_meta_grpc::MetaGrpcClientConf;
+use common_meta_grpc::MetaGrpcClientPool;
+use once_cell::sync::Lazy;
 
 // Since there is a pending dependency issue,
 // StoreApiProvider is temporarily moved from store-api-sdk
 //
 // @see https://github.com/datafuselabs/databend/issues/1929
 
+/// The meta clients shared by all the providers in the process.
+static GLOBAL_META_CLIENT_POOL: Lazy<MetaGrpcClientPool> = Lazy::new(MetaGrpcClientPool::default);
+
 #[derive(Clone)]
 pub struct MetaClientProvider {
     grpc_conf: MetaGrpcClientConf,
@@ -36,20 +42,22 @@ impl MetaClientProvider {
     }
 
     /// Get meta async client, trait is defined in MetaApi.
+    /// Clients of the same config are shared, with their connections kept warm.
     pub async fn try_get_meta_client(&self) -> Result<Arc<MetaGrpcClient>> {
-        let client = MetaGrpcClient::try_new(&self.grpc_conf).await?;
-        Ok(Arc::new(client))
+        GLOBAL_META_CLIENT_POOL.acquire(&self.grpc_conf).await
     }
 
     /// Get kv async client, operations trait defined in KVApi.
+    /// The checksum headers are always stripped on read, `kv_checksum` only decides the writes.
     pub async fn try_get_kv_client(&self) -> Result<Arc<dyn KVApi>> {
         let local = self.grpc_conf.meta_service_config.address.is_empty();
+        let checksum = self.grpc_conf.kv_checksum;
         if local {
             let meta_store = common_meta_embedded::MetaEmbedded::get_meta().await?;
-            Ok(meta_store)
+            Ok(Arc::new(ChecksumKVApi::create(meta_store, checksum)))
         } else {
-            let client = MetaGrpcClient::try_new(&self.grpc_conf).await?;
-            Ok(Arc::new(client))
+            let client = self.try_get_meta_client().await?;
+            Ok(Arc::new(ChecksumKVApi::create(client, checksum)))
         }
     }
 }
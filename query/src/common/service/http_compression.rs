@@ -0,0 +1,135 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use poem::error::InternalServerError;
+use poem::http::header;
+use poem::http::HeaderMap;
+use poem::http::HeaderValue;
+use poem::Body;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+use tokio_stream::StreamExt;
+
+/// Gzip compress the responses if the client advertises it by `Accept-Encoding`.
+///
+/// Responses smaller than `min_size` are sent as is. The body is compressed chunk by chunk,
+/// so streaming responses are not buffered in memory.
+pub struct HttpCompression {
+    min_size: usize,
+}
+
+impl HttpCompression {
+    pub fn create(min_size: usize) -> HttpCompression {
+        HttpCompression { min_size }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for HttpCompression {
+    type Output = HttpCompressionEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        HttpCompressionEndpoint {
+            inner: ep,
+            min_size: self.min_size,
+        }
+    }
+}
+
+pub struct HttpCompressionEndpoint<E> {
+    inner: E,
+    min_size: usize,
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for HttpCompressionEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let accept_gzip = accepts_gzip(req.headers());
+        let mut response = self.inner.call(req).await?.into_response();
+
+        if !accept_gzip || response.headers().contains_key(header::CONTENT_ENCODING) {
+            return Ok(response);
+        }
+
+        // Read at most min_size bytes to know if the body reaches the threshold,
+        // without waiting for the whole body of a streaming response.
+        let mut stream = Box::pin(response.take_body().into_bytes_stream());
+        let mut prefix = Vec::new();
+        let mut finished = true;
+        while let Some(bytes) = stream.next().await {
+            prefix.extend_from_slice(&bytes.map_err(InternalServerError)?);
+            if prefix.len() >= self.min_size {
+                finished = false;
+                break;
+            }
+        }
+
+        if finished {
+            response.set_body(prefix);
+            return Ok(response);
+        }
+
+        let compressed = async_stream::try_stream! {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&prefix)?;
+            encoder.flush()?;
+            yield std::mem::take(encoder.get_mut());
+
+            // Flush per chunk, each chunk of a streaming response reaches the client
+            // as soon as it is produced.
+            while let Some(bytes) = stream.next().await {
+                encoder.write_all(&bytes?)?;
+                encoder.flush()?;
+                yield std::mem::take(encoder.get_mut());
+            }
+            yield encoder.finish()?;
+        };
+
+        let headers = response.headers_mut();
+        headers.remove(header::CONTENT_LENGTH);
+        headers.insert(header::CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+        response.set_body(Body::from_bytes_stream::<_, _, std::io::Error>(compressed));
+        Ok(response)
+    }
+}
+
+// Accept-Encoding: gzip, deflate;q=0.5, *;q=0
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|coding| {
+            let mut parts = coding.split(';').map(|part| part.trim());
+            let name = parts.next().unwrap_or_default();
+            let disabled = parts.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .map(|q| q <= 0.0)
+                    .unwrap_or(false)
+            });
+            (name.eq_ignore_ascii_case("gzip") || name == "*") && !disabled
+        })
+}
@@ -0,0 +1,111 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Instant;
+
+use metrics::counter;
+use metrics::histogram;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+
+pub static METRIC_HTTP_REQUESTS: &str = "http.requests";
+pub static METRIC_HTTP_REQUEST_DURATION: &str = "http.request_duration";
+
+/// The paths not served by any route are counted under this route,
+/// to keep the labels bounded.
+pub const UNKNOWN_ROUTE: &str = "unknown";
+
+/// Count the requests and record their latency per route,
+/// the counts are labeled with the status class of the reply: 2xx, 3xx, 4xx or 5xx.
+///
+/// The metrics go to the global recorder, thus are exported by the metric api at `/metrics`.
+/// Only apply it to routes without path parameters, the route label is the request path.
+pub struct HttpMetrics {
+    service: String,
+}
+
+impl HttpMetrics {
+    pub fn create(service: impl Into<String>) -> HttpMetrics {
+        HttpMetrics {
+            service: service.into(),
+        }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for HttpMetrics {
+    type Output = HttpMetricsEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        HttpMetricsEndpoint {
+            inner: ep,
+            service: self.service.clone(),
+        }
+    }
+}
+
+pub struct HttpMetricsEndpoint<E> {
+    inner: E,
+    service: String,
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for HttpMetricsEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let path = req.uri().path().to_string();
+        let started = Instant::now();
+
+        let res = self.inner.call(req).await.map(IntoResponse::into_response);
+        let status = match &res {
+            Ok(response) => response.status(),
+            Err(err) => err.status(),
+        };
+        let route = match status {
+            StatusCode::NOT_FOUND => UNKNOWN_ROUTE.to_string(),
+            _ => path,
+        };
+
+        let route_labels = [("service", self.service.clone()), ("route", route.clone())];
+        histogram!(
+            METRIC_HTTP_REQUEST_DURATION,
+            started.elapsed(),
+            &route_labels
+        );
+
+        let labels = [
+            ("service", self.service.clone()),
+            ("route", route),
+            ("status", status_class(status).to_string()),
+        ];
+        counter!(METRIC_HTTP_REQUESTS, 1, &labels);
+        res
+    }
+}
+
+fn status_class(status: StatusCode) -> &'static str {
+    if status.is_server_error() {
+        "5xx"
+    } else if status.is_client_error() {
+        "4xx"
+    } else if status.is_redirection() {
+        "3xx"
+    } else {
+        "2xx"
+    }
+}
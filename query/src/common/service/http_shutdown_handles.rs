@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use common_base::tokio::sync::oneshot;
 use common_base::tokio::task::JoinHandle;
@@ -28,6 +29,10 @@ use poem::listener::RustlsConfig;
 use poem::listener::TcpListener;
 use poem::Endpoint;
 
+/// How long a graceful shutdown waits for the in-flight requests to complete,
+/// the connections still open after it are dropped.
+pub const HTTP_SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub struct HttpShutdownHandler {
     service_name: String,
     join_handle: Option<JoinHandle<std::io::Result<()>>>,
@@ -80,7 +85,7 @@ impl HttpShutdownHandler {
             poem::Server::new_with_acceptor(acceptor).run_with_graceful_shutdown(
                 ep,
                 rx.map(|_| ()),
-                None,
+                Some(HTTP_SHUTDOWN_DRAIN_TIMEOUT),
             ),
         );
         self.join_handle = Some(join_handle);
@@ -88,6 +93,8 @@ impl HttpShutdownHandler {
         Ok(addr)
     }
 
+    /// A graceful shutdown stops accepting and returns once the in-flight requests are drained,
+    /// in `HTTP_SHUTDOWN_DRAIN_TIMEOUT` at most, otherwise the server is aborted at once.
     pub async fn shutdown(&mut self, graceful: bool) {
         if graceful {
             if let Some(abort_handle) = self.abort_handle.take() {
@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::IntoResponse;
+use poem::Middleware;
+use poem::Request;
+use poem::Response;
+
+/// Reply 504 if the endpoint does not finish within the timeout,
+/// so a hung handler does not hold the connection forever.
+pub struct HttpTimeout {
+    timeout: Duration,
+}
+
+impl HttpTimeout {
+    pub fn create(timeout: Duration) -> HttpTimeout {
+        HttpTimeout { timeout }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for HttpTimeout {
+    type Output = HttpTimeoutEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        HttpTimeoutEndpoint {
+            inner: ep,
+            timeout: self.timeout,
+        }
+    }
+}
+
+pub struct HttpTimeoutEndpoint<E> {
+    inner: E,
+    timeout: Duration,
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for HttpTimeoutEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        match tokio::time::timeout(self.timeout, self.inner.call(req)).await {
+            Ok(res) => res.map(IntoResponse::into_response),
+            Err(_elapsed) => Err(poem::Error::from_status(StatusCode::GATEWAY_TIMEOUT)),
+        }
+    }
+}
@@ -11,5 +11,17 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+mod http_compression;
+mod http_metrics;
 mod http_shutdown_handles;
+mod http_timeout;
+pub use http_compression::HttpCompression;
+pub use http_compression::HttpCompressionEndpoint;
+pub use http_metrics::HttpMetrics;
+pub use http_metrics::HttpMetricsEndpoint;
+pub use http_metrics::METRIC_HTTP_REQUESTS;
+pub use http_metrics::METRIC_HTTP_REQUEST_DURATION;
 pub use http_shutdown_handles::HttpShutdownHandler;
+pub use http_shutdown_handles::HTTP_SHUTDOWN_DRAIN_TIMEOUT;
+pub use http_timeout::HttpTimeout;
+pub use http_timeout::HttpTimeoutEndpoint;
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::fs;
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
 use std::str::FromStr;
 
 use clap::Parser;
@@ -176,4 +178,45 @@ impl Config {
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
+
+    /// The config with the secrets(passwords, storage keys) masked, used to echo the config back.
+    pub fn redacted(&self) -> Self {
+        let mask = "******".to_string();
+        let mut config = self.clone();
+        config.meta.meta_password = mask.clone();
+        config.storage.s3.access_key_id = mask.clone();
+        config.storage.s3.secret_access_key = mask.clone();
+        config.storage.azure_storage_blob.account = mask.clone();
+        config.storage.azure_storage_blob.master_key = mask;
+        config
+    }
+
+    /// Parse a listen address like `127.0.0.1:8080`, `[::1]:8080` or `localhost:8080`,
+    /// a host name is resolved to its first address.
+    pub fn parse_listen_address(address: &str) -> Result<SocketAddr> {
+        if let Ok(addr) = address.parse::<SocketAddr>() {
+            return Ok(addr);
+        }
+
+        let invalid = |cause: String| {
+            ErrorCode::InvalidConfig(format!(
+                "Invalid listen address '{}', cause: {}",
+                address, cause
+            ))
+        };
+        address
+            .to_socket_addrs()
+            .map_err(|e| invalid(e.to_string()))?
+            .next()
+            .ok_or_else(|| invalid("no address resolved".to_string()))
+    }
+
+    /// Parse a listen address from a host and a port, the host may be an IPv6 address like `::1`.
+    pub fn parse_listen_host_port(host: &str, port: u16) -> Result<SocketAddr> {
+        if host.contains(':') && !host.starts_with('[') {
+            Self::parse_listen_address(&format!("[{}]:{}", host, port))
+        } else {
+            Self::parse_listen_address(&format!("{}:{}", host, port))
+        }
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt;
+use std::time::Duration;
 
 use clap::Args;
 use common_grpc::RpcClientConf;
@@ -30,6 +31,7 @@ pub const META_PASSWORD: &str = "META_PASSWORD";
 pub const META_EMBEDDED_DIR: &str = "META_EMBEDDED_DIR";
 pub const META_RPC_TLS_SERVER_ROOT_CA_CERT: &str = "META_RPC_TLS_SERVER_ROOT_CA_CERT";
 pub const META_RPC_TLS_SERVICE_DOMAIN_NAME: &str = "META_RPC_TLS_SERVICE_DOMAIN_NAME";
+pub const META_KV_CHECKSUM: &str = "META_KV_CHECKSUM";
 
 /// Meta config group.
 #[derive(Clone, PartialEq, Serialize, Deserialize, Args)]
@@ -55,6 +57,48 @@ pub struct MetaConfig {
     )]
     pub meta_client_timeout_in_second: u64,
 
+    #[clap(
+        long,
+        default_value = "0",
+        help = "Close meta connections idle for longer than it, in seconds, 0 means never"
+    )]
+    pub meta_client_idle_timeout_in_second: u64,
+
+    #[clap(
+        long,
+        default_value = "0",
+        help = "Fail meta requests not replied in time, in seconds, 0 means never"
+    )]
+    pub meta_request_timeout_in_second: u64,
+
+    #[clap(
+        long,
+        default_value = "0",
+        help = "How many times an idempotent meta request is retried on a transient failure"
+    )]
+    pub meta_client_max_retries: u32,
+
+    #[clap(
+        long,
+        default_value = "2",
+        help = "How many times a meta request is sent at most when its token is renewed"
+    )]
+    pub meta_client_max_token_renewals: u32,
+
+    #[clap(
+        long,
+        default_value = "0",
+        help = "How many meta requests are in flight at most, the excess wait, 0 means unbounded"
+    )]
+    pub meta_client_max_concurrent_requests: usize,
+
+    #[clap(
+        long,
+        env = META_KV_CHECKSUM,
+        help = "Store a checksum alongside each kv value and verify it on read"
+    )]
+    pub meta_kv_checksum: bool,
+
     #[clap(
         long,
         env = "META_RPC_TLS_SERVER_ROOT_CA_CERT",
@@ -79,6 +123,12 @@ impl Default for MetaConfig {
             meta_username: "root".to_string(),
             meta_password: "".to_string(),
             meta_client_timeout_in_second: 10,
+            meta_client_idle_timeout_in_second: 0,
+            meta_request_timeout_in_second: 0,
+            meta_client_max_retries: 0,
+            meta_client_max_token_renewals: 2,
+            meta_client_max_concurrent_requests: 0,
+            meta_kv_checksum: false,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
         }
@@ -104,6 +154,7 @@ impl MetaConfig {
             String,
             META_RPC_TLS_SERVICE_DOMAIN_NAME
         );
+        env_helper!(mut_config, meta, meta_kv_checksum, bool, META_KV_CHECKSUM);
     }
 
     pub fn is_tls_enabled(&self) -> bool {
@@ -134,6 +185,15 @@ impl MetaConfig {
             meta_service_config: meta_config.clone(),
             kv_service_config: meta_config,
             client_timeout_in_second: self.meta_client_timeout_in_second,
+            pool_idle_timeout_in_second: self.meta_client_idle_timeout_in_second,
+            kv_checksum: self.meta_kv_checksum,
+            request_timeout: match self.meta_request_timeout_in_second {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            },
+            max_retries: self.meta_client_max_retries,
+            max_token_renewals: self.meta_client_max_token_renewals,
+            max_concurrent_requests: self.meta_client_max_concurrent_requests,
         }
     }
 }
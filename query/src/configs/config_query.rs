@@ -62,6 +62,15 @@ const QUERY_TABLE_ENGINE_MEMORY_ENABLED: &str = "QUERY_TABLE_ENGINE_MEMORY_ENABL
 const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB_ENABLED";
 
 const QUERY_MANAGEMENT_MODE: &str = "QUERY_MANAGEMENT_MODE";
+const QUERY_MAX_EXPRESSION_DEPTH: &str = "QUERY_MAX_EXPRESSION_DEPTH";
+const QUERY_MAX_EXPRESSION_ACTIONS: &str = "QUERY_MAX_EXPRESSION_ACTIONS";
+const QUERY_READ_ONLY: &str = "QUERY_READ_ONLY";
+const QUERY_MANAGEMENT_MODE_SUPERUSER: &str = "QUERY_MANAGEMENT_MODE_SUPERUSER";
+const QUERY_MANAGEMENT_MODE_ALLOWED_STATEMENTS: &str = "QUERY_MANAGEMENT_MODE_ALLOWED_STATEMENTS";
+const QUERY_HTTP_COMPRESSION_MIN_SIZE: &str = "QUERY_HTTP_COMPRESSION_MIN_SIZE";
+const QUERY_HTTP_CONFIG_TIMEOUT_MS: &str = "QUERY_HTTP_CONFIG_TIMEOUT_MS";
+const QUERY_FLIGHT_MAX_ROWS_PER_BATCH: &str = "QUERY_FLIGHT_MAX_ROWS_PER_BATCH";
+const QUERY_NULL_DISPLAY: &str = "QUERY_NULL_DISPLAY";
 
 /// Query config group.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Args)]
@@ -208,6 +217,43 @@ pub struct QueryConfig {
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     #[clap(long, env = QUERY_MANAGEMENT_MODE)]
     pub management_mode: bool,
+
+    /// Maximum nesting depth of an expression, deeper expressions are rejected when building the pipeline.
+    #[clap(long, env = QUERY_MAX_EXPRESSION_DEPTH, default_value = "1024")]
+    pub max_expression_depth: u64,
+
+    /// Maximum number of actions of an expression executor, larger projections are rejected when building the pipeline.
+    #[clap(long, env = QUERY_MAX_EXPRESSION_ACTIONS, default_value = "8192")]
+    pub max_expression_actions: u64,
+
+    /// If in read-only mode, only queries(select/show/describe etc.) are allowed.
+    #[clap(long, env = QUERY_READ_ONLY)]
+    pub read_only: bool,
+
+    /// The user allowed to bypass the management mode denials, empty means no one.
+    #[clap(long, env = QUERY_MANAGEMENT_MODE_SUPERUSER, default_value = "")]
+    pub management_mode_superuser: String,
+
+    /// The statements allowed in management mode, by plan name and separated by comma,
+    /// e.g. `SelectPlan,InsertPlan`. Empty means the built-in ones.
+    #[clap(long, env = QUERY_MANAGEMENT_MODE_ALLOWED_STATEMENTS, default_value = "")]
+    pub management_mode_allowed_statements: String,
+
+    /// Responses smaller than this(bytes) are not gzip compressed, even if the client accepts it.
+    #[clap(long, env = QUERY_HTTP_COMPRESSION_MIN_SIZE, default_value = "1024")]
+    pub http_compression_min_size: u64,
+
+    /// Timeout(ms) of the config route of the http api, a request taking longer is replied with 504.
+    #[clap(long, env = QUERY_HTTP_CONFIG_TIMEOUT_MS, default_value = "5000")]
+    pub http_config_timeout_ms: u64,
+
+    /// Maximum rows of a record batch sent by the flight service, larger blocks are split into several batches.
+    #[clap(long, env = QUERY_FLIGHT_MAX_ROWS_PER_BATCH, default_value = "65536")]
+    pub flight_max_rows_per_batch: u64,
+
+    /// The text of null in the text output of query results, e.g. `NULL`, `\N` or empty.
+    #[clap(long, env = QUERY_NULL_DISPLAY, default_value = "NULL")]
+    pub null_display: String,
 }
 
 impl Default for QueryConfig {
@@ -250,6 +296,15 @@ impl Default for QueryConfig {
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
             management_mode: false,
+            max_expression_depth: 1024,
+            max_expression_actions: 8192,
+            read_only: false,
+            management_mode_superuser: "".to_string(),
+            management_mode_allowed_statements: "".to_string(),
+            http_compression_min_size: 1024,
+            http_config_timeout_ms: 5000,
+            flight_max_rows_per_batch: 65536,
+            null_display: "NULL".to_string(),
         }
     }
 }
@@ -488,5 +543,56 @@ impl QueryConfig {
             bool,
             QUERY_MANAGEMENT_MODE
         );
+        env_helper!(
+            mut_config,
+            query,
+            max_expression_depth,
+            u64,
+            QUERY_MAX_EXPRESSION_DEPTH
+        );
+        env_helper!(
+            mut_config,
+            query,
+            max_expression_actions,
+            u64,
+            QUERY_MAX_EXPRESSION_ACTIONS
+        );
+        env_helper!(mut_config, query, read_only, bool, QUERY_READ_ONLY);
+        env_helper!(
+            mut_config,
+            query,
+            management_mode_superuser,
+            String,
+            QUERY_MANAGEMENT_MODE_SUPERUSER
+        );
+        env_helper!(
+            mut_config,
+            query,
+            management_mode_allowed_statements,
+            String,
+            QUERY_MANAGEMENT_MODE_ALLOWED_STATEMENTS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            http_compression_min_size,
+            u64,
+            QUERY_HTTP_COMPRESSION_MIN_SIZE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            http_config_timeout_ms,
+            u64,
+            QUERY_HTTP_CONFIG_TIMEOUT_MS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            flight_max_rows_per_batch,
+            u64,
+            QUERY_FLIGHT_MAX_ROWS_PER_BATCH
+        );
+        env_helper!(mut_config, query, null_display, String, QUERY_NULL_DISPLAY);
     }
 }
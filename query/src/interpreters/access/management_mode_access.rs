@@ -17,9 +17,73 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
+use common_tracing::tracing;
 
+use crate::configs::Config;
 use crate::sessions::QueryContext;
 
+/// The plans allowed in management-mode by default, by `PlanNode::name()`.
+pub const MANAGEMENT_MODE_ALLOWED_STATEMENTS: &[&str] = &[
+    "StagePlan",
+    "CreateDatabasePlan",
+    "ShowCreateDatabasePlan",
+    "DropDatabasePlan",
+    "CreateTablePlan",
+    "DescribeTablePlan",
+    "DescribeStagePlan",
+    "DropTablePlan",
+    "ShowCreateTablePlan",
+    "CreateUser",
+    "AlterUser",
+    "DropUser",
+    "GrantPrivilegePlan",
+    "RevokePrivilegePlan",
+    "CreateUserStagePlan",
+    "DropUserStagePlan",
+    "ShowGrantsPlan",
+    "UseTenant",
+    "CreateUDFPlan",
+    "DropUDFPlan",
+    "UseDatabasePlan",
+    // Allow select from system.* tables, like show tables;
+    "SelectPlan",
+    "AlterUDF",
+];
+
+/// The statements allowed in management-mode, by plan name:
+/// the configured `management_mode_allowed_statements`,
+/// or `MANAGEMENT_MODE_ALLOWED_STATEMENTS` if none is configured.
+pub fn management_mode_allowed_statements(conf: &Config) -> Vec<String> {
+    let allowed_statements = conf
+        .query
+        .management_mode_allowed_statements
+        .split(',')
+        .map(|plan| plan.trim())
+        .filter(|plan| !plan.is_empty())
+        .map(|plan| plan.to_string())
+        .collect::<Vec<_>>();
+
+    if allowed_statements.is_empty() {
+        MANAGEMENT_MODE_ALLOWED_STATEMENTS
+            .iter()
+            .map(|plan| plan.to_string())
+            .collect()
+    } else {
+        allowed_statements
+    }
+}
+
+/// Attached to the query log of a statement the management-mode superuser runs past a denial.
+pub const MANAGEMENT_MODE_SUPERUSER_BYPASS: &str = "management-mode superuser bypass";
+
+/// How a plan passes the management-mode check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManagementModeGrant {
+    Allowed,
+    /// Denied in management-mode, but run by the superuser.
+    SuperuserBypass,
+}
+
 pub struct ManagementModeAccess {
     ctx: Arc<QueryContext>,
 }
@@ -30,45 +94,44 @@ impl ManagementModeAccess {
     }
 
     // Check what we can do if in management mode.
-    pub fn check(&self, plan: &PlanNode) -> Result<()> {
+    pub fn check(&self, plan: &PlanNode) -> Result<ManagementModeGrant> {
         // Allows for management-mode.
-        if self.ctx.get_config().query.management_mode {
-            return match plan {
-                PlanNode::Stage(_)
-                | PlanNode::CreateDatabase(_)
-                | PlanNode::ShowCreateDatabase(_)
-                | PlanNode::DropDatabase(_)
-                | PlanNode::CreateTable(_)
-                | PlanNode::DescribeTable(_)
-                | PlanNode::DescribeStage(_)
-                | PlanNode::DropTable(_)
-                | PlanNode::ShowCreateTable(_)
-                | PlanNode::CreateUser(_)
-                | PlanNode::AlterUser(_)
-                | PlanNode::DropUser(_)
-                | PlanNode::GrantPrivilege(_)
-                | PlanNode::RevokePrivilege(_)
-                | PlanNode::CreateUserStage(_)
-                | PlanNode::DropUserStage(_)
-                | PlanNode::ShowGrants(_)
-                | PlanNode::UseTenant(_)
-                | PlanNode::CreateUDF(_)
-                | PlanNode::DropUDF(_)
-                | PlanNode::UseDatabase(_)
-                | PlanNode::Select(_) // Allow select from system.* tables, like show tables;
-                | PlanNode::AlterUDF(_) => Ok(()),
-                _ => Err(ErrorCode::ManagementModePermissionDenied(format!(
+        let conf = self.ctx.get_config();
+        if conf.query.management_mode {
+            let allowed_statements = management_mode_allowed_statements(&conf);
+            return if allowed_statements
+                .iter()
+                .any(|allowed| allowed == plan.name())
+            {
+                Ok(ManagementModeGrant::Allowed)
+            } else if self.is_superuser()? {
+                tracing::warn!(
+                    "Management-mode superuser bypass for operation:{:?}, query:{}",
+                    plan.name(),
+                    self.ctx.get_query_str()
+                );
+                Ok(ManagementModeGrant::SuperuserBypass)
+            } else {
+                Err(ErrorCode::ManagementModePermissionDenied(format!(
                     "Access denied for operation:{:?} in management-mode",
                     plan.name()
-                ))),
+                )))
             };
         } else {
             match plan {
                 PlanNode::UseTenant(_) => Err(ErrorCode::ManagementModePermissionDenied(
                     "Access denied:'USE TENANT' only used in management-mode",
                 )),
-                _ => Ok(()),
+                _ => Ok(ManagementModeGrant::Allowed),
             }
         }
     }
+
+    fn is_superuser(&self) -> Result<bool> {
+        let superuser = &self.ctx.get_config().query.management_mode_superuser;
+        if superuser.is_empty() {
+            return Ok(false);
+        }
+        Ok(&self.ctx.get_current_user()?.name == superuser)
+    }
 }
@@ -13,5 +13,11 @@
 // limitations under the License.
 
 mod management_mode_access;
+mod read_only_access;
 
+pub use management_mode_access::management_mode_allowed_statements;
 pub use management_mode_access::ManagementModeAccess;
+pub use management_mode_access::ManagementModeGrant;
+pub use management_mode_access::MANAGEMENT_MODE_ALLOWED_STATEMENTS;
+pub use management_mode_access::MANAGEMENT_MODE_SUPERUSER_BYPASS;
+pub use read_only_access::ReadOnlyAccess;
@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+
+use crate::sessions::QueryContext;
+
+pub struct ReadOnlyAccess {
+    ctx: Arc<QueryContext>,
+}
+
+impl ReadOnlyAccess {
+    pub fn create(ctx: Arc<QueryContext>) -> Self {
+        ReadOnlyAccess { ctx }
+    }
+
+    // Check what we can do if in read-only mode.
+    pub fn check(&self, plan: &PlanNode) -> Result<()> {
+        if !self.ctx.get_config().query.read_only {
+            return Ok(());
+        }
+
+        // Allows for read-only mode, queries and the session level statements.
+        match plan {
+            PlanNode::Select(_)
+            | PlanNode::Explain(_)
+            | PlanNode::DescribeTable(_)
+            | PlanNode::DescribeStage(_)
+            | PlanNode::ShowCreateTable(_)
+            | PlanNode::ShowCreateDatabase(_)
+            | PlanNode::ShowGrants(_)
+            | PlanNode::UseDatabase(_)
+            | PlanNode::SetVariable(_) => Ok(()),
+            _ => Err(ErrorCode::ReadOnlyMode(format!(
+                "Access denied for operation:{:?} in read-only mode",
+                plan.name()
+            ))),
+        }
+    }
+}
@@ -20,6 +20,9 @@ use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::access::ManagementModeAccess;
+use crate::interpreters::access::ManagementModeGrant;
+use crate::interpreters::access::ReadOnlyAccess;
+use crate::interpreters::access::MANAGEMENT_MODE_SUPERUSER_BYPASS;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::InterpreterQueryLog;
@@ -31,6 +34,7 @@ pub struct InterceptorInterpreter {
     inner: InterpreterPtr,
     query_log: InterpreterQueryLog,
     management_mode_access: ManagementModeAccess,
+    read_only_access: ReadOnlyAccess,
 }
 
 impl InterceptorInterpreter {
@@ -40,7 +44,8 @@ impl InterceptorInterpreter {
             plan: plan.clone(),
             inner,
             query_log: InterpreterQueryLog::create(ctx.clone(), plan),
-            management_mode_access: ManagementModeAccess::create(ctx),
+            management_mode_access: ManagementModeAccess::create(ctx.clone()),
+            read_only_access: ReadOnlyAccess::create(ctx),
         }
     }
 }
@@ -55,8 +60,13 @@ impl Interpreter for InterceptorInterpreter {
         &self,
         input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
-        // Management mode access check.
-        self.management_mode_access.check(&self.plan)?;
+        // Management mode access check, a superuser bypass is audited in the query log.
+        let grant = self.management_mode_access.check(&self.plan)?;
+        if grant == ManagementModeGrant::SuperuserBypass {
+            self.query_log.set_extra(MANAGEMENT_MODE_SUPERUSER_BYPASS);
+        }
+        // Read-only mode access check.
+        self.read_only_access.check(&self.plan)?;
 
         let result_stream = self.inner.execute(input_stream).await?;
         let metric_stream =
@@ -20,6 +20,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
 use common_exception::Result;
+use common_infallible::RwLock;
 use common_planners::PlanNode;
 
 use crate::sessions::QueryContext;
@@ -92,11 +93,21 @@ pub struct LogEvent {
 pub struct InterpreterQueryLog {
     ctx: Arc<QueryContext>,
     plan: PlanNode,
+    extra: RwLock<String>,
 }
 
 impl InterpreterQueryLog {
     pub fn create(ctx: Arc<QueryContext>, plan: PlanNode) -> Self {
-        InterpreterQueryLog { ctx, plan }
+        InterpreterQueryLog {
+            ctx,
+            plan,
+            extra: RwLock::new("".to_string()),
+        }
+    }
+
+    /// Set the `extra` column of the events logged afterwards, e.g. an audit note.
+    pub fn set_extra(&self, extra: &str) {
+        *self.extra.write() = extra.to_string();
     }
 
     async fn write_log(&self, event: &LogEvent) -> Result<()> {
@@ -240,7 +251,7 @@ impl InterpreterQueryLog {
             exception: "".to_string(),
             stack_trace: "".to_string(),
             server_version: "".to_string(),
-            extra: "".to_string(),
+            extra: self.extra.read().clone(),
         };
 
         self.write_log(&log_event).await
@@ -329,7 +340,7 @@ impl InterpreterQueryLog {
             exception: "".to_string(),
             stack_trace: "".to_string(),
             server_version: "".to_string(),
-            extra: "".to_string(),
+            extra: self.extra.read().clone(),
         };
 
         self.write_log(&log_event).await
@@ -50,6 +50,9 @@ mod interpreter_user_create;
 mod interpreter_user_drop;
 mod plan_schedulers;
 
+pub use access::management_mode_allowed_statements;
+pub use access::MANAGEMENT_MODE_ALLOWED_STATEMENTS;
+pub use access::MANAGEMENT_MODE_SUPERUSER_BYPASS;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_copy::CopyInterpreter;
@@ -140,23 +140,31 @@ impl PipelineBuilder {
 
     fn visit_expression(&mut self, plan: &ExpressionPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
+        let limits = self.ctx.get_expression_limits();
+        let collapse_nullables = self.ctx.get_settings().get_enable_collapse_nullables()? != 0;
         pipeline.add_simple_transform(|| {
-            Ok(Box::new(ExpressionTransform::try_create(
-                plan.input.schema(),
-                plan.schema.clone(),
-                plan.exprs.clone(),
-            )?))
+            Ok(Box::new(
+                ExpressionTransform::try_create(
+                    plan.input.schema(),
+                    plan.schema.clone(),
+                    plan.exprs.clone(),
+                    limits,
+                )?
+                .with_collapse_nullables(collapse_nullables),
+            ))
         })?;
         Ok(pipeline)
     }
 
     fn visit_projection(&mut self, node: &ProjectionPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
+        let limits = self.ctx.get_expression_limits();
         pipeline.add_simple_transform(|| {
             Ok(Box::new(ProjectionTransform::try_create(
                 node.input.schema(),
                 node.schema(),
                 node.expr.clone(),
+                limits,
             )?))
         })?;
         Ok(pipeline)
@@ -216,10 +224,12 @@ impl PipelineBuilder {
 
     fn visit_filter(&mut self, node: &FilterPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
+        let limits = self.ctx.get_expression_limits();
         pipeline.add_simple_transform(|| {
             Ok(Box::new(WhereTransform::try_create(
                 node.schema(),
                 node.predicate.clone(),
+                limits,
             )?))
         })?;
         Ok(pipeline)
@@ -227,10 +237,12 @@ impl PipelineBuilder {
 
     fn visit_having(&mut self, node: &HavingPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*node.input)?;
+        let limits = self.ctx.get_expression_limits();
         pipeline.add_simple_transform(|| {
             Ok(Box::new(HavingTransform::try_create(
                 node.schema(),
                 node.predicate.clone(),
+                limits,
             )?))
         })?;
         Ok(pipeline)
@@ -38,6 +38,7 @@ pub use transform_aggregator_partial::AggregatorPartialTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
 pub use transform_expression::ExpressionTransform;
+pub use transform_expression_executor::DuplicateAlias;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::HavingTransform;
 pub use transform_filter::WhereTransform;
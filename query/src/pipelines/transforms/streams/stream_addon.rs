@@ -22,6 +22,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataValue;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
@@ -65,8 +66,13 @@ impl AddOnStream {
 
                     default_expr_fields.push(f.clone());
                     default_exprs.push(expression);
-                } else {
+                } else if f.is_nullable() {
                     default_nonexpr_fields.push(f.clone());
+                } else {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column {} is not nullable and has no default value, it must be specified",
+                        f.name()
+                    )));
                 }
             }
         }
@@ -99,6 +105,7 @@ impl AddOnStream {
                 block.add_column(expr_block.try_column_by_name(f.name())?.clone(), f.clone())?;
         }
 
+        // The missing columns without default expression are nullable, filled with nulls.
         for f in &self.default_nonexpr_fields {
             let column = DataColumn::Constant(
                 DataValue::new_from_data_type(f.data_type(), f.is_nullable()),
@@ -19,6 +19,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::Expression;
+use common_planners::ExpressionLimits;
 use common_streams::SendableDataBlockStream;
 use tokio_stream::StreamExt;
 
@@ -43,6 +44,7 @@ pub struct ExpressionTransform {
     // The final schema(Build by plan_builder.expression).
     input: Arc<dyn Processor>,
     executor: ExpressionExecutor,
+    collapse_nullables: bool,
 }
 
 impl ExpressionTransform {
@@ -50,21 +52,31 @@ impl ExpressionTransform {
         input_schema: DataSchemaRef,
         output_schema: DataSchemaRef,
         exprs: Vec<Expression>,
+        limits: ExpressionLimits,
     ) -> Result<Self> {
-        let executor = ExpressionExecutor::try_create(
+        let executor = ExpressionExecutor::try_create_with_limits(
             "expression executor",
             input_schema,
             output_schema,
             exprs,
             false,
+            limits,
         )?;
         executor.validate()?;
 
         Ok(ExpressionTransform {
             input: Arc::new(EmptyProcessor::create()),
             executor,
+            collapse_nullables: false,
         })
     }
+
+    /// Collapse the nullable columns without null rows of the output blocks, see `DataBlock::collapse_nullables`.
+    #[must_use]
+    pub fn with_collapse_nullables(mut self, collapse_nullables: bool) -> Self {
+        self.collapse_nullables = collapse_nullables;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -88,11 +100,17 @@ impl Processor for ExpressionTransform {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let executor = self.executor.clone();
+        let collapse_nullables = self.collapse_nullables;
         let input_stream = self.input.execute().await?;
 
-        let executor_fn = |executor: &ExpressionExecutor,
-                           block: Result<DataBlock>|
-         -> Result<DataBlock> { executor.execute(&block?) };
+        let executor_fn =
+            move |executor: &ExpressionExecutor, block: Result<DataBlock>| -> Result<DataBlock> {
+                let block = executor.execute(&block?)?;
+                match collapse_nullables {
+                    true => Ok(block.collapse_nullables()),
+                    false => Ok(block),
+                }
+            };
 
         let stream =
             input_stream.filter_map(move |v| executor_fn(&executor, v).map(Some).transpose());
@@ -20,26 +20,47 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_datavalues::DataValue;
+use common_datavalues::PhysicalDataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ActionFunction;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
+use common_planners::ExpressionLimits;
 use common_tracing::tracing;
 
+/// How the executor resolves different expressions aliased with the same name,
+/// e.g. a+1 as c, b+1 as c.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuplicateAlias {
+    /// Reject the expressions.
+    Error,
+    /// The first aliased expression wins.
+    FirstWins,
+    /// The last aliased expression wins.
+    LastWins,
+}
+
 /// ExpressionExecutor is a helper struct for expressions and projections
 /// Aggregate functions is not covered, because all expressions in aggregate functions functions are executed.
 #[derive(Debug, Clone)]
 pub struct ExpressionExecutor {
     // description of this executor
     description: String,
-    _input_schema: DataSchemaRef,
+    input_schema: DataSchemaRef,
     output_schema: DataSchemaRef,
     chain: Arc<ExpressionChain>,
     // whether to perform alias action in executor
     alias_project: bool,
+    duplicate_alias: DuplicateAlias,
+    // the output schema with the columns in the emitted order, see order_by_input
+    projection_schema: DataSchemaRef,
+    // the columns of a chain of constants only, computed once in try_create
+    constant_columns: Option<HashMap<String, DataColumnWithField>>,
 }
 
 impl ExpressionExecutor {
@@ -50,21 +71,203 @@ impl ExpressionExecutor {
         exprs: Vec<Expression>,
         alias_project: bool,
     ) -> Result<Self> {
-        let chain = ExpressionChain::try_create(input_schema.clone(), &exprs)?;
+        Self::try_create_with_limits(
+            description,
+            input_schema,
+            output_schema,
+            exprs,
+            alias_project,
+            ExpressionLimits::default(),
+        )
+    }
+
+    pub fn try_create_with_limits(
+        description: &str,
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        exprs: Vec<Expression>,
+        alias_project: bool,
+        limits: ExpressionLimits,
+    ) -> Result<Self> {
+        let chain = ExpressionChain::try_create_with_limits(input_schema.clone(), &exprs, &limits)?;
+
+        Self::try_create_with_chain(
+            description,
+            input_schema,
+            output_schema,
+            chain,
+            alias_project,
+            DuplicateAlias::Error,
+        )
+    }
+
+    pub fn try_create_with_chain(
+        description: &str,
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        chain: ExpressionChain,
+        alias_project: bool,
+        duplicate_alias: DuplicateAlias,
+    ) -> Result<Self> {
+        if alias_project && duplicate_alias == DuplicateAlias::Error {
+            Self::check_alias_unique(&chain)?;
+        }
+
+        let constant_columns = Self::constant_columns(&chain);
 
         Ok(Self {
             description: description.to_string(),
-            _input_schema: input_schema,
-            output_schema,
+            input_schema,
+            output_schema: output_schema.clone(),
             chain: Arc::new(chain),
             alias_project,
+            duplicate_alias,
+            projection_schema: output_schema,
+            constant_columns,
         })
     }
 
+    // Returns the columns of the chain if all the actions are constants, e.g. SELECT 1, 'x'.
+    // Alias actions are not folded, such chains are executed as usual.
+    fn constant_columns(chain: &ExpressionChain) -> Option<HashMap<String, DataColumnWithField>> {
+        if chain.actions.is_empty() {
+            return None;
+        }
+
+        let mut columns = HashMap::with_capacity(chain.actions.len());
+        for action in chain.actions.iter() {
+            let constant = match action {
+                ExpressionAction::Constant(constant) => constant,
+                _ => return None,
+            };
+
+            let column = DataColumnWithField::new(
+                DataColumn::Constant(constant.value.clone(), 0),
+                DataField::new(
+                    constant.name.as_str(),
+                    constant.data_type.clone(),
+                    constant.value.is_null(),
+                ),
+            );
+            columns.insert(constant.name.clone(), column);
+        }
+        Some(columns)
+    }
+
+    /// Emit the columns passed through from the input in the order of the input schema,
+    /// followed by the computed columns in the order of the output schema.
+    pub fn order_by_input(mut self, order_by_input: bool) -> Self {
+        self.projection_schema = self.output_schema.clone();
+
+        if order_by_input {
+            let input_fields = self.input_schema.fields();
+            let position = |name: &str| input_fields.iter().position(|f| f.name() == name);
+
+            let mut passthrough = vec![];
+            let mut computed = vec![];
+            for f in self.output_schema.fields() {
+                match position(f.name()) {
+                    Some(index) => passthrough.push((index, f.clone())),
+                    None => computed.push(f.clone()),
+                }
+            }
+            passthrough.sort_by_key(|(index, _)| *index);
+
+            let mut fields: Vec<DataField> = passthrough.into_iter().map(|(_, f)| f).collect();
+            fields.extend(computed);
+            self.projection_schema = DataSchemaRefExt::create(fields);
+        }
+        self
+    }
+
+    // Different expressions aliased with the same name are not supported, e.g. a+1 as c, b+1 as c.
+    fn check_alias_unique(chain: &ExpressionChain) -> Result<()> {
+        let mut alias_args: HashMap<&str, &str> = HashMap::new();
+        for action in chain.actions.iter() {
+            if let ExpressionAction::Alias(alias) = action {
+                if let Some(arg_name) = alias_args.insert(&alias.name, &alias.arg_name) {
+                    return Err(ErrorCode::UnImplement(format!(
+                        "Duplicate alias name :{}, for {} and {}",
+                        alias.name, arg_name, alias.arg_name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The column must be of the physical type declared by the action, a NULL column is
+    // accepted if the action is nullable.
+    fn check_column_type(
+        name: &str,
+        expected: &DataType,
+        is_nullable: bool,
+        column: &DataColumn,
+    ) -> Result<()> {
+        let physical_type = column.physical_type();
+        if physical_type == expected.to_physical_type()
+            || (is_nullable && physical_type == PhysicalDataType::Null)
+        {
+            return Ok(());
+        }
+
+        Err(ErrorCode::LogicalError(format!(
+            "Column {} is declared as {:?}, but the executed column is {:?}",
+            name,
+            expected,
+            column.data_type()
+        )))
+    }
+
     pub fn validate(&self) -> Result<()> {
         Ok(())
     }
 
+    /// Execute on a sample block to surface the type and schema errors before running over
+    /// a stream, the produced data is discarded and its schema is returned.
+    pub fn dry_run(&self, sample: &DataBlock) -> Result<DataSchemaRef> {
+        for field in self.input_schema.fields() {
+            let column = sample.try_column_by_name(field.name())?;
+            let physical_type = column.physical_type();
+            if physical_type != field.data_type().to_physical_type()
+                && !(field.is_nullable() && physical_type == PhysicalDataType::Null)
+            {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Column {} of the sample is {:?}, but the input schema of {} declares {:?}",
+                    field.name(),
+                    column.data_type(),
+                    self.description,
+                    field.data_type()
+                )));
+            }
+        }
+
+        let block = self.execute(sample)?;
+        Ok(block.schema().clone())
+    }
+
+    /// Execute on a shared block. The columns of the block are passed through without
+    /// evaluating the chain if the expressions are an identity projection of the block.
+    pub fn execute_arc(&self, block: Arc<DataBlock>) -> Result<DataBlock> {
+        if self.is_identity(block.schema()) {
+            return Ok(DataBlock::create(
+                self.projection_schema.clone(),
+                block.columns().to_vec(),
+            ));
+        }
+        self.execute(&block)
+    }
+
+    // Only the input columns are read, and they are projected in the same order.
+    fn is_identity(&self, input_schema: &DataSchemaRef) -> bool {
+        self.projection_schema.as_ref() == input_schema.as_ref()
+            && self
+                .chain
+                .actions
+                .iter()
+                .all(|action| matches!(action, ExpressionAction::Input(_)))
+    }
+
     pub fn execute(&self, block: &DataBlock) -> Result<DataBlock> {
         tracing::debug!(
             "({:#}) execute, actions: {:?}",
@@ -72,33 +275,69 @@ impl ExpressionExecutor {
             self.chain.actions
         );
 
-        let mut column_map: HashMap<&str, DataColumnWithField> = HashMap::new();
+        let mut column_map = HashMap::new();
+        let mut alias_map = HashMap::new();
+        self.execute_block(block, &mut column_map, &mut alias_map)
+    }
+
+    /// Execute on blocks of the input schema, the buffers of the executor are allocated
+    /// once and reused by all the blocks.
+    pub fn execute_batch(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        tracing::debug!(
+            "({:#}) execute batch of {} blocks, actions: {:?}",
+            self.description,
+            blocks.len(),
+            self.chain.actions
+        );
+
+        if let Some(block) = blocks.iter().find(|b| b.schema() != &self.input_schema) {
+            return Err(ErrorCode::LogicalError(format!(
+                "Blocks of a batch must have the input schema {:?}, but got {:?}",
+                self.input_schema,
+                block.schema()
+            )));
+        }
 
-        let mut alias_map: HashMap<&str, &DataColumnWithField> = HashMap::new();
+        let mut column_map = HashMap::new();
+        let mut alias_map = HashMap::new();
+        let mut results = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            column_map.clear();
+            alias_map.clear();
+            results.push(self.execute_block(block, &mut column_map, &mut alias_map)?);
+        }
+        Ok(results)
+    }
 
-        // supported a + 1 as b, a + 1 as c
-        // supported a + 1 as a, a as b
-        // !currently not supported a+1 as c, b+1 as c
-        let mut alias_action_map: HashMap<&str, Vec<&str>> = HashMap::new();
+    fn execute_block<'a>(
+        &'a self,
+        block: &'a DataBlock,
+        column_map: &mut HashMap<&'a str, DataColumnWithField>,
+        alias_map: &mut HashMap<&'a str, &'a str>,
+    ) -> Result<DataBlock> {
+        if let Some(constant_columns) = &self.constant_columns {
+            return self.execute_constants(constant_columns, block.num_rows());
+        }
 
-        for f in block.schema().fields().iter() {
-            let column =
-                DataColumnWithField::new(block.try_column_by_name(f.name())?.clone(), f.clone());
+        for (f, column) in block.schema().fields().iter().zip(block.columns()) {
+            let column = DataColumnWithField::new(column.clone(), f.clone());
             column_map.insert(f.name(), column);
         }
 
         let rows = block.num_rows();
         for action in self.chain.actions.iter() {
-            if let ExpressionAction::Alias(alias) = action {
-                if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
-                    v.push(alias.name.as_str());
-                } else {
-                    alias_action_map.insert(alias.arg_name.as_str(), vec![alias.name.as_str()]);
-                }
-            }
-
+            // The column is already computed by an identical sub-expression or read from the
+            // block, a non-deterministic function is only reused if it comes from the block.
             if column_map.contains_key(action.column_name()) {
-                continue;
+                let reusable = match action {
+                    ExpressionAction::Function(f) => {
+                        block.schema().has_field(&f.name) || f.is_deterministic()?
+                    }
+                    _ => true,
+                };
+                if reusable {
+                    continue;
+                }
             }
 
             match action {
@@ -111,11 +350,27 @@ impl ExpressionExecutor {
                     column_map.insert(input.name.as_str(), column);
                 }
                 ExpressionAction::Function(f) => {
-                    let column_with_field = self.execute_function(&mut column_map, f, rows)?;
+                    let column_with_field = self.execute_function(column_map, f, rows)?;
+                    if cfg!(debug_assertions) {
+                        Self::check_column_type(
+                            &f.name,
+                            &f.return_type,
+                            f.is_nullable,
+                            column_with_field.column(),
+                        )?;
+                    }
                     column_map.insert(f.name.as_str(), column_with_field);
                 }
                 ExpressionAction::Constant(constant) => {
                     let column = DataColumn::Constant(constant.value.clone(), rows);
+                    if cfg!(debug_assertions) {
+                        Self::check_column_type(
+                            &constant.name,
+                            &constant.data_type,
+                            constant.value.is_null(),
+                            &column,
+                        )?;
+                    }
 
                     let column = DataColumnWithField::new(
                         column,
@@ -128,45 +383,116 @@ impl ExpressionExecutor {
 
                     column_map.insert(constant.name.as_str(), column);
                 }
+                ExpressionAction::Reuse(reuse) => {
+                    let column = column_map.get(reuse.arg_name.as_str()).ok_or_else(|| {
+                        ErrorCode::LogicalError(format!(
+                            "Reused column {} of {} must be prepared before it",
+                            reuse.arg_name, reuse.name
+                        ))
+                    })?;
+
+                    let column = DataColumnWithField::new(
+                        column.column().clone(),
+                        DataField::new(
+                            reuse.name.as_str(),
+                            reuse.return_type.clone(),
+                            reuse.is_nullable,
+                        ),
+                    );
+                    column_map.insert(reuse.name.as_str(), column);
+                }
                 _ => {}
             }
         }
 
+        // supported a + 1 as b, a + 1 as c
+        // supported a + 1 as a, a as b
+        // a+1 as c, b+1 as c is resolved by duplicate_alias
         if self.alias_project {
-            for (k, v) in alias_action_map.iter() {
-                let column = column_map.get(k).ok_or_else(|| {
-                    ErrorCode::LogicalError("Arguments must be prepared before alias transform")
-                })?;
-
-                for name in v.iter() {
-                    match alias_map.insert(name, column) {
-                        Some(_) => Err(ErrorCode::UnImplement(format!(
-                            "Duplicate alias name :{}",
-                            name
-                        ))),
-                        _ => Ok(()),
-                    }?;
+            for action in self.chain.actions.iter() {
+                let alias = match action {
+                    ExpressionAction::Alias(alias) => alias,
+                    _ => continue,
+                };
+
+                if !column_map.contains_key(alias.arg_name.as_str()) {
+                    return Err(ErrorCode::LogicalError(
+                        "Arguments must be prepared before alias transform",
+                    ));
+                }
+
+                let arg_name = alias.arg_name.as_str();
+                if let Some(previous) = alias_map.insert(alias.name.as_str(), arg_name) {
+                    match self.duplicate_alias {
+                        // Checked in try_create, kept as a defensive assertion.
+                        DuplicateAlias::Error => {
+                            return Err(ErrorCode::UnImplement(format!(
+                                "Duplicate alias name :{}",
+                                alias.name
+                            )));
+                        }
+                        DuplicateAlias::FirstWins => {
+                            alias_map.insert(alias.name.as_str(), previous);
+                        }
+                        DuplicateAlias::LastWins => {}
+                    }
+                    tracing::debug!(
+                        "({:#}) duplicate alias name {}, resolved by {:?}",
+                        self.description,
+                        alias.name,
+                        self.duplicate_alias
+                    );
                 }
             }
         }
 
-        let mut project_columns = Vec::with_capacity(self.output_schema.fields().len());
-        for f in self.output_schema.fields() {
-            let column = match alias_map.get(f.name().as_str()) {
-                Some(data_column) => data_column,
-                None => column_map.get(f.name().as_str()).ok_or_else(|| {
-                    ErrorCode::LogicalError(format!(
-                        "Projection column: {} not exists in {:?}, there are bugs!",
-                        f.name(),
-                        column_map.keys()
-                    ))
-                })?,
+        let mut project_columns = Vec::with_capacity(self.projection_schema.fields().len());
+        for f in self.projection_schema.fields() {
+            let name = match alias_map.get(f.name().as_str()) {
+                Some(arg_name) => *arg_name,
+                None => f.name().as_str(),
             };
+            // The output schema of a hand-built plan may not match its expressions.
+            let column = column_map.get(name).ok_or_else(|| {
+                let output_names = self
+                    .projection_schema
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().as_str())
+                    .collect::<Vec<_>>();
+                ErrorCode::UnknownColumn(format!(
+                    "Projection column {} is not produced by the expressions, output schema: [{}]",
+                    f.name(),
+                    output_names.join(", ")
+                ))
+            })?;
             project_columns.push(column.column().clone());
         }
         // projection to remove unused columns
         Ok(DataBlock::create(
-            self.output_schema.clone(),
+            self.projection_schema.clone(),
+            project_columns,
+        ))
+    }
+
+    // The constants are only replicated to the rows of the block.
+    fn execute_constants(
+        &self,
+        constant_columns: &HashMap<String, DataColumnWithField>,
+        rows: usize,
+    ) -> Result<DataBlock> {
+        let mut project_columns = Vec::with_capacity(self.projection_schema.fields().len());
+        for f in self.projection_schema.fields() {
+            let column = constant_columns.get(f.name()).ok_or_else(|| {
+                ErrorCode::UnknownColumn(format!(
+                    "Projection column {} is not produced by the expressions",
+                    f.name()
+                ))
+            })?;
+            project_columns.push(column.column().resize_constant(rows));
+        }
+        Ok(DataBlock::create(
+            self.projection_schema.clone(),
             project_columns,
         ))
     }
@@ -178,12 +504,14 @@ impl ExpressionExecutor {
         f: &ActionFunction,
         rows: usize,
     ) -> Result<DataColumnWithField> {
-        // check if it's cached
         let mut arg_columns = Vec::with_capacity(f.arg_names.len());
 
         for arg in f.arg_names.iter() {
             let column = column_map.get(arg.as_str()).cloned().ok_or_else(|| {
-                ErrorCode::LogicalError("Arguments must be prepared before function transform")
+                ErrorCode::LogicalError(format!(
+                    "Argument {} of function {} must be prepared before function transform",
+                    arg, f.name
+                ))
             })?;
             arg_columns.push(column);
         }
@@ -20,6 +20,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_planners::Expression;
+use common_planners::ExpressionLimits;
 use common_streams::CorrectWithSchemaStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
@@ -39,8 +40,12 @@ pub struct FilterTransform<const HAVING: bool> {
 }
 
 impl<const HAVING: bool> FilterTransform<HAVING> {
-    pub fn try_create(schema: DataSchemaRef, predicate: Expression) -> Result<Self> {
-        let predicate_executor = Self::expr_executor(&schema, &predicate)?;
+    pub fn try_create(
+        schema: DataSchemaRef,
+        predicate: Expression,
+        limits: ExpressionLimits,
+    ) -> Result<Self> {
+        let predicate_executor = Self::expr_executor(&schema, &predicate, limits)?;
         predicate_executor.validate()?;
 
         Ok(FilterTransform {
@@ -50,16 +55,21 @@ impl<const HAVING: bool> FilterTransform<HAVING> {
         })
     }
 
-    fn expr_executor(schema: &DataSchemaRef, expr: &Expression) -> Result<ExpressionExecutor> {
+    fn expr_executor(
+        schema: &DataSchemaRef,
+        expr: &Expression,
+        limits: ExpressionLimits,
+    ) -> Result<ExpressionExecutor> {
         let expr_field = expr.to_data_field(schema)?;
         let expr_schema = DataSchemaRefExt::create(vec![expr_field]);
 
-        ExpressionExecutor::try_create(
+        ExpressionExecutor::try_create_with_limits(
             "filter expression executor",
             schema.clone(),
             expr_schema,
             vec![expr.clone()],
             false,
+            limits,
         )
     }
 
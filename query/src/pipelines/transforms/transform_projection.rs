@@ -20,6 +20,7 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::Expression;
+use common_planners::ExpressionLimits;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use tokio_stream::StreamExt;
@@ -38,13 +39,15 @@ impl ProjectionTransform {
         input_schema: DataSchemaRef,
         output_schema: DataSchemaRef,
         exprs: Vec<Expression>,
+        limits: ExpressionLimits,
     ) -> Result<Self> {
-        let executor = ExpressionExecutor::try_create(
+        let executor = ExpressionExecutor::try_create_with_limits(
             "projection executor",
             input_schema,
             output_schema,
             exprs,
             true,
+            limits,
         )?;
 
         Ok(ProjectionTransform {
@@ -25,6 +25,7 @@ use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
 
+use crate::common::service::HttpCompression;
 use crate::common::service::HttpShutdownHandler;
 use crate::configs::Config;
 use crate::servers::http::v1::query_route;
@@ -66,6 +67,12 @@ curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' -
             .nest("/v1/query", query_route())
             .at("/v1/streaming_load", put(streaming_load))
             .data(self.session_manager.clone())
+            .with(HttpCompression::create(
+                self.session_manager
+                    .get_conf()
+                    .query
+                    .http_compression_min_size as usize,
+            ))
             .boxed()
     }
 
@@ -208,7 +208,7 @@ async fn query_page_handler(
             let resp = query
                 .get_response_page(page_no, &wait_type, false)
                 .await
-                .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
+                .map_err(|err| poem::Error::from_string(err.message(), err.to_http_status()))?;
             Ok(Json(QueryResponse::from_internal(query_id, resp)))
         }
         None => Err(query_id_not_found(query_id)),
@@ -239,7 +239,7 @@ pub(crate) async fn query_handler(
             let resp = query
                 .get_response_page(0, &wait_type, true)
                 .await
-                .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
+                .map_err(|err| poem::Error::from_string(err.message(), err.to_http_status()))?;
             Ok(Json(QueryResponse::from_internal(
                 query.id.to_string(),
                 resp,
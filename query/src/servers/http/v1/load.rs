@@ -122,7 +122,7 @@ pub async fn streaming_load(
 
     let plan = PlanParser::parse(insert_sql, context.clone())
         .await
-        .map_err(InternalServerError)?;
+        .map_err(|err| poem::Error::from_string(err.message(), err.to_http_status()))?;
     context.attach_query_str(insert_sql);
 
     // validate plan
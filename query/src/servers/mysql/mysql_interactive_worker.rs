@@ -168,7 +168,9 @@ impl<W: std::io::Write + Send> AsyncMysqlShim<W> for InteractiveWorker<W> {
             ));
         }
 
-        let mut writer = DFQueryResultWriter::create(writer);
+        let sessions = self.session.get_sessions_manager();
+        let null_display = &sessions.get_conf().query.null_display;
+        let mut writer = DFQueryResultWriter::create(writer, null_display);
 
         let instant = Instant::now();
         let blocks = self.base.do_query(query).await;
@@ -28,17 +28,27 @@ use msql_srv::*;
 
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
+    /// The text of the nulls rendered as text, e.g. nested in a struct.
+    null_display: String,
 }
 
 impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
-    pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+    pub fn create(
+        inner: QueryResultWriter<'a, W>,
+        null_display: &str,
+    ) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            null_display: null_display.to_string(),
+        }
     }
 
     pub fn write(&mut self, query_result: Result<(Vec<DataBlock>, String)>) -> Result<()> {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer)?,
+                Ok((blocks, extra_info)) => {
+                    Self::ok(blocks, extra_info, &self.null_display, writer)?
+                }
                 Err(error) => Self::err(&error, writer)?,
             }
         }
@@ -48,6 +58,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
     fn ok(
         blocks: Vec<DataBlock>,
         extra_info: String,
+        null_display: &str,
         dataset_writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
         // XXX: num_columns == 0 may is error?
@@ -185,7 +196,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     row_writer.write_col(v)?
                                 }
                                 (DataType::Struct(_), DataValue::Struct(_)) => {
-                                    let serializer = data_type.create_serializer();
+                                    let serializer = data_type.create_text_serializer(null_display);
                                     row_writer.write_col(serializer.serialize_value(&val)?)?
                                 }
                                 (_, v) => {
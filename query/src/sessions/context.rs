@@ -36,6 +36,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_meta_types::UserInfo;
+use common_planners::ExpressionLimits;
 use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::PlanNode;
@@ -242,6 +243,15 @@ impl QueryContext {
         self.shared.conf.clone()
     }
 
+    /// The limits of the expressions an executor is built from, see `max_expression_*` in config.
+    pub fn get_expression_limits(&self) -> ExpressionLimits {
+        let conf = &self.shared.conf.query;
+        ExpressionLimits {
+            max_depth: conf.max_expression_depth as usize,
+            max_actions: conf.max_expression_actions as usize,
+        }
+    }
+
     pub fn get_tenant(&self) -> String {
         self.shared.get_tenant()
     }
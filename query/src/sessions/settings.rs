@@ -36,7 +36,8 @@ impl Settings {
         ("storage_read_buffer_size", u64, 1024 * 1024, "The size of buffer in bytes for buffered reader of dal. By default, it is 1MB."),
         ("storage_occ_backoff_init_delay_ms", u64, 5, "The initial retry delay in millisecond. By default,  it is 5 ms."),
         ("storage_occ_backoff_max_delay_ms", u64, 20 * 1000, "The maximum  back off delay in millisecond, once the retry interval reaches this value, it stops increasing. By default, it is 20 seconds."),
-        ("storage_occ_backoff_max_elapsed_ms", u64, 120 * 1000, "The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes")
+        ("storage_occ_backoff_max_elapsed_ms", u64, 120 * 1000, "The maximum elapsed time after the occ starts, beyond which there will be no more retries. By default, it is 2 minutes"),
+        ("enable_collapse_nullables", u64, 0, "Mark the nullable columns without null rows as not nullable after the expression stage. By default, it is 0 (disabled).")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -93,11 +93,13 @@ impl Executor {
         }
 
         let mut pipeline = self.build_pipeline(child).await?;
+        let limits = self.ctx.get_expression_limits();
         pipeline.add_simple_transform(|| {
             Ok(Box::new(ProjectionTransform::try_create(
                 input_schema.clone(),
                 output_schema.clone(),
                 exprs.clone(),
+                limits,
             )?))
         })?;
         Ok(pipeline)
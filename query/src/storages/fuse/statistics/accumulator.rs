@@ -78,9 +78,9 @@ impl StatisticsAccumulator {
 
                 let null_count = match col {
                     DataColumn::Array(s) => s.null_count(),
-                    DataColumn::Constant(v, _) => {
+                    DataColumn::Constant(v, rows) => {
                         if v.is_null() {
-                            1
+                            *rows
                         } else {
                             0
                         }
@@ -444,7 +444,12 @@ impl BloomFilter {
     ///
     /// The design of skipping Nulls are arguably correct. For now we do the same as databricks.
     /// See the design of databricks https://docs.databricks.com/delta/optimizations/bloom-filters.html
+    /// A column of the NULL type adds nothing.
     pub fn add(&mut self, column: &DataColumn) -> Result<()> {
+        if column.data_type() == DataType::Null {
+            return Ok(());
+        }
+
         if !Self::is_supported_type(&column.data_type()) {
             return Err(ErrorCode::BadArguments(format!(
                 "Unsupported data type: {} ",
@@ -490,7 +495,7 @@ impl BloomFilter {
         Ok(())
     }
 
-    /// Check the existence of the data. The data value should not be Null.
+    /// Check the existence of the data. Nulls are never added, so a Null is never found.
     /// Use BloomFilter::is_supported_value to check before using this method.
     ///
     ///
@@ -503,6 +508,10 @@ impl BloomFilter {
     ///
     /// ```
     pub fn find(&self, val: DataValue) -> Result<bool> {
+        if val.is_null() {
+            return Ok(false);
+        }
+
         if !Self::is_supported_value(&val) {
             return Err(ErrorCode::BadArguments(format!(
                 "Unsupported data value: {} ",
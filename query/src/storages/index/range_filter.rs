@@ -40,6 +40,13 @@ pub struct ColumnStatistics {
     pub in_memory_size: u64,
 }
 
+impl ColumnStatistics {
+    /// Whether the column contains any null value, min/max only cover the non-null values.
+    pub fn has_null(&self) -> bool {
+        self.null_count > 0
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RangeFilter {
     origin: DataSchemaRef,
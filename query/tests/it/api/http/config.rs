@@ -15,9 +15,12 @@
  *
  */
 use common_base::tokio;
+use databend_query::api::http::v1::config::access_policy_handler;
 use databend_query::api::http::v1::config::config_handler;
+use databend_query::api::http::v1::config::AccessPolicy;
 use databend_query::configs::Config;
 use poem::get;
+use poem::http::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::http::Uri;
@@ -29,21 +32,201 @@ use pretty_assertions::assert_eq; // for `app.oneshot()`
 
 #[tokio::test]
 async fn test_config() -> common_exception::Result<()> {
-    let conf = Config::default();
+    let mut conf = Config::default();
+    conf.meta.meta_password = "my-meta-secret".to_string();
+    conf.storage.s3.secret_access_key = "my-s3-secret".to_string();
     let cluster_router = Route::new()
         .at("/v1/config", get(config_handler))
         .data(conf.clone());
 
-    let response = cluster_router
-        .call(
+    // Default json
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/config"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert!(!body.contains("my-meta-secret"));
+        assert!(!body.contains("my-s3-secret"));
+        let actual = serde_json::from_str::<Config>(&body)?;
+        assert_eq!(actual, conf.redacted());
+        assert_eq!(actual.meta.meta_password, "******");
+        assert_eq!(actual.storage.s3.secret_access_key, "******");
+    }
+
+    // Json
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/config?format=json"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(serde_json::from_str::<Config>(&body)?, conf.redacted());
+    }
+
+    // Toml
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/config?format=toml"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(Config::load_from_toml_str(&body)?, conf.redacted());
+    }
+
+    // Unsupported
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/config?format=xml"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_config_accept() -> common_exception::Result<()> {
+    let mut conf = Config::default();
+    conf.meta.meta_password = "my-meta-secret".to_string();
+    conf.storage.s3.secret_access_key = "my-s3-secret".to_string();
+    let cluster_router = Route::new()
+        .at("/v1/config", get(config_handler))
+        .data(conf.clone());
+
+    let get_config = |accept: &'static str| {
+        cluster_router.call(
             Request::builder()
                 .uri(Uri::from_static("/v1/config"))
+                .header(header::ACCEPT, accept)
                 .method(Method::GET)
                 .finish(),
         )
-        .await
-        .unwrap();
+    };
+
+    // Any
+    {
+        let response = get_config("*/*").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(serde_json::from_str::<Config>(&body)?, conf.redacted());
+    }
+
+    // Toml, the first supported one wins
+    {
+        let response = get_config("application/xml, application/toml;q=0.9")
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(Config::load_from_toml_str(&body)?, conf.redacted());
+    }
+
+    // Text
+    {
+        let response = get_config("text/plain").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_string().await.unwrap();
+        assert!(!body.contains("my-meta-secret"));
+        assert!(!body.contains("my-s3-secret"));
+        let lines = body.lines().collect::<Vec<_>>();
+        assert!(lines.contains(&"meta.meta_password=******"));
+        assert!(lines.contains(&"storage.s3.secret_access_key=******"));
+        assert!(lines.contains(&"meta.meta_kv_checksum=false"));
+    }
+
+    // Not acceptable
+    {
+        let response = get_config("application/xml").await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_config_access_policy() -> common_exception::Result<()> {
+    let get_policy = |conf: Config| async move {
+        let router = Route::new()
+            .at("/v1/config/access_policy", get(access_policy_handler))
+            .data(conf);
+
+        let response = router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/config/access_policy"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_vec().await.unwrap();
+        serde_json::from_slice::<AccessPolicy>(&body)
+    };
+
+    // The default allowed plans.
+    {
+        let mut conf = Config::default();
+        conf.query.management_mode = true;
+
+        let policy = get_policy(conf).await?;
+        assert!(policy.management_mode);
+        assert!(policy
+            .allowed_statements
+            .contains(&"CreateDatabasePlan".to_string()));
+        assert!(policy
+            .allowed_statements
+            .contains(&"SelectPlan".to_string()));
+        assert!(!policy
+            .allowed_statements
+            .contains(&"InsertPlan".to_string()));
+    }
+
+    // The configured allowed plans.
+    {
+        let mut conf = Config::default();
+        conf.query.management_mode = true;
+        conf.query.management_mode_allowed_statements = "CreateTablePlan, InsertPlan".to_string();
+
+        let policy = get_policy(conf).await?;
+        assert!(policy.management_mode);
+        assert_eq!(policy.allowed_statements, vec![
+            "CreateTablePlan".to_string(),
+            "InsertPlan".to_string()
+        ]);
+    }
 
-    assert_eq!(response.status(), StatusCode::OK);
     Ok(())
 }
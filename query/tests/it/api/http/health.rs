@@ -21,13 +21,19 @@ use poem::http::Method;
 use poem::http::StatusCode;
 use poem::http::Uri;
 use poem::Endpoint;
+use poem::EndpointExt;
 use poem::Request;
 use poem::Route;
 use pretty_assertions::assert_eq;
 
+use crate::tests::SessionManagerBuilder;
+
 #[tokio::test]
 async fn test_health() -> common_exception::Result<()> {
-    let cluster_router = Route::new().at("/v1/health", get(health_handler));
+    let sessions = SessionManagerBuilder::create().build()?;
+    let cluster_router = Route::new()
+        .at("/v1/health", get(health_handler))
+        .data(sessions);
     // health check
     let response = cluster_router
         .call(
@@ -40,6 +46,33 @@ async fn test_health() -> common_exception::Result<()> {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(body, r#"{"status":"pass"}"#);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_health_not_serving() -> common_exception::Result<()> {
+    // No session can be created.
+    let sessions = SessionManagerBuilder::create().max_sessions(0).build()?;
+    let cluster_router = Route::new()
+        .at("/v1/health", get(health_handler))
+        .data(sessions);
+
+    let response = cluster_router
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/health"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(body, r#"{"status":"fail"}"#);
 
     Ok(())
 }
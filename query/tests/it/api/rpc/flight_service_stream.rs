@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::IpcSchema;
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::api::FlightDataStream;
+use futures::StreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_flight_data_stream_max_rows_per_batch() -> Result<()> {
+    let rows = 100_000;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("n", DataType::Null, true),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        Series::new((0..rows as i64).collect::<Vec<_>>()).into(),
+        DataColumn::Constant(DataValue::Null, rows),
+    ]);
+
+    let (sender, receiver) = tokio::sync::mpsc::channel(1);
+    sender.send(Ok(block)).await.unwrap();
+    drop(sender);
+
+    let arrow_schema = Arc::new(schema.to_arrow());
+    let ipc_fields = default_ipc_fields(arrow_schema.fields());
+    let ipc_schema = IpcSchema {
+        fields: ipc_fields.clone(),
+        is_little_endian: true,
+    };
+
+    let stream = FlightDataStream::create(receiver, ipc_fields, 10_000);
+    let batches = stream.collect::<Vec<_>>().await;
+    assert_eq!(batches.len(), 10);
+
+    for (index, batch) in batches.into_iter().enumerate() {
+        let flight_data = batch.unwrap();
+        let record_batch = deserialize_batch(
+            &flight_data,
+            arrow_schema.clone(),
+            &ipc_schema,
+            &Default::default(),
+        )?;
+
+        assert_eq!(record_batch.num_rows(), 10_000);
+        let values = record_batch.column(0).clone().into_series();
+        assert_eq!(
+            values.try_get(0)?,
+            DataValue::Int64(Some(index as i64 * 10_000))
+        );
+        let nulls = record_batch.column(1);
+        assert_eq!(nulls.len(), 10_000);
+        assert_eq!(nulls.null_count(), 10_000);
+    }
+
+    Ok(())
+}
@@ -15,4 +15,5 @@
 mod flight_actions;
 mod flight_dispatcher;
 mod flight_service;
+mod flight_service_stream;
 mod flight_tickets;
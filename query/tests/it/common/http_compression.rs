@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_base::tokio;
+use databend_query::common::service::HttpCompression;
+use flate2::read::GzDecoder;
+use poem::get;
+use poem::handler;
+use poem::http::header;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[handler]
+fn large_handler() -> String {
+    "databend ".repeat(1024)
+}
+
+#[handler]
+fn small_handler() -> String {
+    "databend".to_string()
+}
+
+#[tokio::test]
+async fn test_http_compression() -> common_exception::Result<()> {
+    let route = Route::new()
+        .at("/large", get(large_handler))
+        .at("/small", get(small_handler))
+        .with(HttpCompression::create(1024));
+
+    let request = |uri: &'static str, accept_encoding: Option<&'static str>| {
+        let mut builder = Request::builder()
+            .uri(Uri::from_static(uri))
+            .method(Method::GET);
+        if let Some(accept_encoding) = accept_encoding {
+            builder = builder.header(header::ACCEPT_ENCODING, accept_encoding);
+        }
+        builder.finish()
+    };
+
+    // Large response, client accepts gzip
+    {
+        let response = route
+            .call(request("/large", Some("gzip, deflate")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_ENCODING).unwrap(),
+            "gzip"
+        );
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let mut decoded = String::new();
+        GzDecoder::new(body.as_slice()).read_to_string(&mut decoded)?;
+        assert_eq!(decoded, "databend ".repeat(1024));
+    }
+
+    // Large response, client does not advertise gzip
+    {
+        let response = route.call(request("/large", None)).await.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(body, "databend ".repeat(1024));
+
+        let response = route
+            .call(request("/large", Some("gzip;q=0")))
+            .await
+            .unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
+    // Small response stays uncompressed
+    {
+        let response = route.call(request("/small", Some("gzip"))).await.unwrap();
+        assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+        let body = response.into_body().into_string().await.unwrap();
+        assert_eq!(body, "databend");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_metrics::dump_metric_samples;
+use common_metrics::init_default_metrics_recorder;
+use common_metrics::try_handle;
+use common_metrics::MetricValue;
+use databend_query::common::service::HttpMetrics;
+use poem::get;
+use poem::handler;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[handler]
+fn ok_handler() -> String {
+    "databend".to_string()
+}
+
+#[handler]
+fn fail_handler() -> poem::Result<String> {
+    Err(poem::Error::from_status(StatusCode::INTERNAL_SERVER_ERROR))
+}
+
+fn request_count(route: &str, status: &str) -> common_exception::Result<f64> {
+    let samples = dump_metric_samples(try_handle().unwrap())?;
+    let count = samples
+        .iter()
+        .filter(|sample| sample.name == "http_requests")
+        .filter(|sample| {
+            let label = |key: &str| sample.labels.get(key).map(|v| v.as_str());
+            label("service") == Some("test_http_metrics")
+                && label("route") == Some(route)
+                && label("status") == Some(status)
+        })
+        .map(|sample| match sample.value {
+            MetricValue::Counter(v) => v,
+            _ => 0.0,
+        })
+        .sum();
+    Ok(count)
+}
+
+#[tokio::test]
+async fn test_http_metrics() -> common_exception::Result<()> {
+    init_default_metrics_recorder();
+    let route = Route::new()
+        .at("/ok", get(ok_handler))
+        .at("/fail", get(fail_handler))
+        .with(HttpMetrics::create("test_http_metrics"));
+
+    let request = |uri: &'static str| {
+        Request::builder()
+            .uri(Uri::from_static(uri))
+            .method(Method::GET)
+            .finish()
+    };
+
+    for _ in 0..2 {
+        let response = route.call(request("/ok")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    assert!(route.call(request("/fail")).await.is_err());
+    let _ = route.call(request("/not/a/route")).await;
+
+    assert_eq!(request_count("/ok", "2xx")?, 2.0);
+    assert_eq!(request_count("/fail", "5xx")?, 1.0);
+    assert_eq!(request_count("unknown", "4xx")?, 1.0);
+    assert_eq!(request_count("/not/a/route", "4xx")?, 0.0);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::tokio;
+use databend_query::api::http::v1::config::config_handler;
+use databend_query::common::service::HttpTimeout;
+use databend_query::configs::Config;
+use poem::get;
+use poem::handler;
+use poem::http::Method;
+use poem::http::StatusCode;
+use poem::http::Uri;
+use poem::Endpoint;
+use poem::EndpointExt;
+use poem::Request;
+use poem::Route;
+use pretty_assertions::assert_eq;
+
+#[handler]
+async fn slow_handler() -> String {
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    "databend".to_string()
+}
+
+#[tokio::test]
+async fn test_http_timeout() -> common_exception::Result<()> {
+    let conf = Config::default();
+    let config_timeout = Duration::from_millis(conf.query.http_config_timeout_ms);
+    let route = Route::new()
+        .at(
+            "/slow",
+            get(slow_handler).with(HttpTimeout::create(Duration::from_millis(100))),
+        )
+        .at(
+            "/v1/config",
+            get(config_handler).with(HttpTimeout::create(config_timeout)),
+        )
+        .data(conf);
+
+    let request = |uri: &'static str| {
+        Request::builder()
+            .uri(Uri::from_static(uri))
+            .method(Method::GET)
+            .finish()
+    };
+
+    // The slow route is cut off with 504.
+    let error = route.call(request("/slow")).await.unwrap_err();
+    assert_eq!(error.status(), StatusCode::GATEWAY_TIMEOUT);
+
+    // The config route is still served in time.
+    let response = route.call(request("/v1/config")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    Ok(())
+}
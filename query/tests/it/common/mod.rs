@@ -13,3 +13,6 @@
 // limitations under the License.
 
 mod hashtable;
+mod http_compression;
+mod http_metrics;
+mod http_timeout;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::configs::Config;
 use databend_query::configs::LogConfig;
@@ -73,6 +74,15 @@ table_memory_cache_mb_size = 256
 table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
 management_mode = false
+max_expression_depth = 1024
+max_expression_actions = 8192
+read_only = false
+management_mode_superuser = \"\"
+management_mode_allowed_statements = \"\"
+http_compression_min_size = 1024
+http_config_timeout_ms = 5000
+flight_max_rows_per_batch = 65536
+null_display = \"NULL\"
 
 [log]
 log_level = \"INFO\"
@@ -84,6 +94,12 @@ meta_address = \"\"
 meta_username = \"root\"
 meta_password = \"\"
 meta_client_timeout_in_second = 10
+meta_client_idle_timeout_in_second = 0
+meta_request_timeout_in_second = 0
+meta_client_max_retries = 0
+meta_client_max_token_renewals = 2
+meta_client_max_concurrent_requests = 0
+meta_kv_checksum = false
 rpc_tls_meta_server_root_ca_cert = \"\"
 rpc_tls_meta_service_domain_name = \"localhost\"
 
@@ -235,3 +251,32 @@ fn test_initial_dir() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_parse_listen_address() -> Result<()> {
+    // IPv4.
+    let addr = Config::parse_listen_address("127.0.0.1:8080")?;
+    assert_eq!(addr.to_string(), "127.0.0.1:8080");
+
+    // IPv6.
+    let addr = Config::parse_listen_address("[::1]:8080")?;
+    assert!(addr.is_ipv6());
+    assert_eq!(addr.to_string(), "[::1]:8080");
+
+    let addr = Config::parse_listen_host_port("::1", 3307)?;
+    assert_eq!(addr.to_string(), "[::1]:3307");
+
+    let addr = Config::parse_listen_host_port("127.0.0.1", 3307)?;
+    assert_eq!(addr.to_string(), "127.0.0.1:3307");
+
+    // Host name.
+    let addr = Config::parse_listen_address("localhost:8080")?;
+    assert!(addr.ip().is_loopback());
+    assert_eq!(addr.port(), 8080);
+
+    // Invalid, missing the port.
+    let result = Config::parse_listen_address("127.0.0.1");
+    assert_eq!(result.unwrap_err().code(), ErrorCode::invalid_config_code());
+
+    Ok(())
+}
@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::configs::Config;
+use databend_query::interpreters::management_mode_allowed_statements;
 use databend_query::interpreters::InterpreterFactory;
+use databend_query::interpreters::MANAGEMENT_MODE_ALLOWED_STATEMENTS;
 use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_management_mode_access() -> Result<()> {
@@ -129,3 +133,146 @@ async fn test_management_mode_access() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_management_mode_superuser_access() -> Result<()> {
+    // The test context runs as user 'root'.
+    let mut config = Config::default();
+    config.query.management_mode = true;
+
+    // Superuser bypasses the denials.
+    {
+        config.query.management_mode_superuser = "root".to_string();
+        let ctx = crate::tests::create_query_context_with_config(config.clone())?;
+        for query in ["CREATE TABLE t1(a int)", "insert into t1 values(1)"] {
+            let plan = PlanParser::parse(query, ctx.clone()).await?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+            interpreter.execute(None).await?;
+        }
+    }
+
+    // The bypass is audited in the query log.
+    {
+        config.query.management_mode_superuser = "root".to_string();
+        let ctx = crate::tests::create_query_context_with_config(config.clone())?;
+        let plan = PlanParser::parse("CREATE TABLE t1(a int)", ctx.clone()).await?;
+        InterpreterFactory::get(ctx.clone(), plan)?
+            .execute(None)
+            .await?;
+
+        let query = "insert into t1 values(1)";
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        let stream = interpreter.execute(None).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        interpreter.finish().await?;
+
+        let query = "select log_type, query_kind, extra from system.query_log";
+        let plan = PlanParser::parse(query, ctx.clone()).await?;
+        let stream = InterpreterFactory::get(ctx.clone(), plan)?
+            .execute(None)
+            .await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        let expected = vec![
+            "+----------+------------+----------------------------------+",
+            "| log_type | query_kind | extra                            |",
+            "+----------+------------+----------------------------------+",
+            "| 1        | InsertPlan |                                  |",
+            "| 2        | InsertPlan | management-mode superuser bypass |",
+            "+----------+------------+----------------------------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Other users remain restricted.
+    {
+        config.query.management_mode_superuser = "admin".to_string();
+        let ctx = crate::tests::create_query_context_with_config(config)?;
+        let plan = PlanParser::parse("CREATE TABLE t1(a int)", ctx.clone()).await?;
+        InterpreterFactory::get(ctx.clone(), plan)?
+            .execute(None)
+            .await?;
+
+        let plan = PlanParser::parse("insert into t1 values(1)", ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(
+            res.err().unwrap().code(),
+            ErrorCode::management_mode_permission_denied_code()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_management_mode_allowed_statements_access() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        is_err: bool,
+    }
+
+    let mut config = Config::default();
+    config.query.management_mode = true;
+
+    // Empty falls back to the built-in plans.
+    assert_eq!(
+        management_mode_allowed_statements(&config),
+        MANAGEMENT_MODE_ALLOWED_STATEMENTS
+            .iter()
+            .map(|plan| plan.to_string())
+            .collect::<Vec<_>>()
+    );
+
+    // The configured plans replace the built-in ones.
+    config.query.management_mode_allowed_statements = "CreateTablePlan, InsertPlan".to_string();
+    assert_eq!(management_mode_allowed_statements(&config), vec![
+        "CreateTablePlan".to_string(),
+        "InsertPlan".to_string()
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "table-create-access-passed",
+            query: "CREATE TABLE t1(a int)",
+            is_err: false,
+        },
+        Test {
+            name: "insert-access-passed",
+            query: "insert into t1 values(1)",
+            is_err: false,
+        },
+        Test {
+            name: "db-create-denied",
+            query: "CREATE DATABASE db1",
+            is_err: true,
+        },
+        Test {
+            name: "table-drop-denied",
+            query: "DROP TABLE t1",
+            is_err: true,
+        },
+    ];
+
+    let ctx = crate::tests::create_query_context_with_config(config)?;
+    for test in tests {
+        let plan = PlanParser::parse(test.query, ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(test.is_err, res.is_err(), "in test case:{:?}", test.name);
+        if let Err(e) = res {
+            assert_eq!(
+                e.code(),
+                ErrorCode::management_mode_permission_denied_code(),
+                "in test case:{:?}",
+                test.name
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod management_mode_access;
+mod read_only_access;
@@ -0,0 +1,71 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::configs::Config;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::sql::PlanParser;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_read_only_access() -> Result<()> {
+    let mut config = Config::default();
+    config.query.read_only = true;
+    let ctx = crate::tests::create_query_context_with_config(config.clone())?;
+
+    // Select passed.
+    {
+        let plan = PlanParser::parse("SELECT number FROM numbers(3)", ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = interpreter.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(result[0].num_rows(), 3);
+    }
+
+    // Create table denied.
+    {
+        let plan = PlanParser::parse("CREATE TABLE t1(a int)", ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::read_only_mode_code());
+    }
+
+    // Insert denied, the table is created and the insert is planned by a writable context.
+    {
+        let writable_ctx = crate::tests::create_query_context()?;
+        let plan = PlanParser::parse("CREATE TABLE t1(a int)", writable_ctx.clone()).await?;
+        InterpreterFactory::get(writable_ctx.clone(), plan)?
+            .execute(None)
+            .await?;
+
+        let plan = PlanParser::parse("INSERT INTO t1 VALUES(1)", writable_ctx).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::read_only_mode_code());
+    }
+
+    // Composing with management mode, read-only denies what management mode allows.
+    {
+        config.query.management_mode = true;
+        let ctx = crate::tests::create_query_context_with_config(config)?;
+        let plan = PlanParser::parse("CREATE DATABASE db1", ctx.clone()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let res = interpreter.execute(None).await;
+        assert_eq!(res.err().unwrap().code(), ErrorCode::read_only_mode_code());
+    }
+
+    Ok(())
+}
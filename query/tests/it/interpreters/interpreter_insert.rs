@@ -121,3 +121,55 @@ async fn test_insert_into_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_insert_into_interpreter_missing_columns() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    // Create table with nullable and non-nullable columns.
+    {
+        static TEST_QUERY: &str = "create table default.missing_columns_table(a UInt8 not null, b String, c UInt8 not null default 3) Engine = Memory";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Missing nullable column is filled with null, missing column with default value uses it.
+    {
+        static TEST_QUERY: &str = "insert into default.missing_columns_table(a) values(1)";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let _ = executor.execute(None).await?;
+    }
+
+    // Missing non-nullable column without default value is an error.
+    {
+        static TEST_QUERY: &str = "insert into default.missing_columns_table(b) values('b')";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let result = executor.execute(None).await;
+        assert_eq!(
+            result.err().unwrap().message(),
+            "Column a is not nullable and has no default value, it must be specified"
+        );
+    }
+
+    // select.
+    {
+        static TEST_QUERY: &str = "select * from default.missing_columns_table";
+        let plan = PlanParser::parse(TEST_QUERY, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan.clone())?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---+------+---+",
+            "| a | b    | c |",
+            "+---+------+---+",
+            "| 1 | NULL | 3 |",
+            "+---+------+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datavalues::DataType;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
@@ -74,5 +75,21 @@ async fn test_select_interpreter() -> Result<()> {
         ];
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
+
+    {
+        static TEST_QUERY_3: &str = "select NULL";
+        let plan = PlanParser::parse(TEST_QUERY_3, ctx.clone()).await?;
+        // Untyped null literal is inferred as Null type.
+        assert_eq!(plan.schema().field(0).data_type(), &DataType::Null);
+        assert!(plan.schema().field(0).is_nullable());
+
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute(None).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        assert_eq!(block.num_columns(), 1);
+        assert_eq!(block.schema().field(0).data_type(), &DataType::Null);
+        assert!(block.column(0).get_validity().all_null());
+    }
     Ok(())
 }
@@ -15,6 +15,7 @@
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
 mod transform_expression;
+mod transform_expression_executor;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
@@ -43,6 +43,7 @@ async fn test_transform_expression() -> Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.exprs.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
     }
@@ -71,6 +72,46 @@ async fn test_transform_expression() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_expression_collapse_nullables() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    for collapse_nullables in [false, true] {
+        let mut pipeline = Pipeline::create(ctx.clone());
+        let source = test_source.number_source_transform_for_test(8)?;
+        pipeline.add_source(Arc::new(source))?;
+
+        // NULLIF is always nullable, but no row of 0..8 equals to 100.
+        let nullif = Expression::create_scalar_function("nullif", vec![col("number"), lit(100u64)])
+            .alias("n");
+        if let PlanNode::Expression(plan) =
+            PlanBuilder::create(test_source.number_schema_for_test()?)
+                .expression(&[col("number"), nullif], "")?
+                .build()?
+        {
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(
+                    ExpressionTransform::try_create(
+                        plan.input.schema(),
+                        plan.schema.clone(),
+                        plan.exprs.clone(),
+                        ExpressionLimits::default(),
+                    )?
+                    .with_collapse_nullables(collapse_nullables),
+                ))
+            })?;
+        }
+
+        let stream = pipeline.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let field = result[0].schema().field_with_name("n")?;
+        assert_eq!(field.is_nullable(), !collapse_nullables);
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_transform_expression_error() -> Result<()> {
     let ctx = crate::tests::create_query_context()?;
@@ -114,6 +155,7 @@ async fn test_transform_expression_issue2857() -> Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.expr.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
     }
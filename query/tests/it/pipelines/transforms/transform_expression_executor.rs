@@ -0,0 +1,563 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::Function;
+use common_planners::*;
+use databend_query::pipelines::transforms::*;
+
+#[test]
+fn test_expression_executor_duplicate_alias() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::Int64, false)]);
+
+    // a+1 as c
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema.clone(),
+        vec![add(col("a"), lit(1i64)).alias("c")],
+        true,
+    );
+    assert!(executor.is_ok());
+
+    // a+1 as c, b+1 as c
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema,
+        output_schema,
+        vec![
+            add(col("a"), lit(1i64)).alias("c"),
+            add(col("b"), lit(1i64)).alias("c"),
+        ],
+        true,
+    );
+    let error = executor.unwrap_err();
+    assert_eq!(error.code(), ErrorCode::un_implement_code());
+    assert_eq!(
+        error.message(),
+        "Duplicate alias name :c, for (a + 1) and (b + 1)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_duplicate_alias_resolved() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::Int64, false)]);
+    let block = DataBlock::create_by_array(input_schema.clone(), vec![
+        Series::new(vec![1i64, 2]),
+        Series::new(vec![10i64, 20]),
+    ]);
+
+    let run = |duplicate_alias: DuplicateAlias| -> Result<DataBlock> {
+        // a+1 as c, b+1 as c
+        let exprs = vec![
+            add(col("a"), lit(1i64)).alias("c"),
+            add(col("b"), lit(1i64)).alias("c"),
+        ];
+        let chain = ExpressionChain::try_create(input_schema.clone(), &exprs)?;
+        let executor = ExpressionExecutor::try_create_with_chain(
+            "test",
+            input_schema.clone(),
+            output_schema.clone(),
+            chain,
+            true,
+            duplicate_alias,
+        )?;
+        executor.execute(&block)
+    };
+
+    let result = run(DuplicateAlias::FirstWins)?;
+    assert_eq!(result.column(0).to_values()?, vec![
+        DataValue::Int64(Some(2)),
+        DataValue::Int64(Some(3)),
+    ]);
+
+    let result = run(DuplicateAlias::LastWins)?;
+    assert_eq!(result.column(0).to_values()?, vec![
+        DataValue::Int64(Some(11)),
+        DataValue::Int64(Some(21)),
+    ]);
+
+    let error = run(DuplicateAlias::Error).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::un_implement_code());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_commutative_reuse() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![
+        DataField::new("(a + b)", DataType::Int64, false),
+        DataField::new("(b + a)", DataType::Int64, false),
+    ]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema,
+        vec![add(col("a"), col("b")), add(col("b"), col("a"))],
+        false,
+    )?;
+
+    let block = DataBlock::create_by_array(input_schema, vec![
+        Series::new(vec![1i64, 2, 3]),
+        Series::new(vec![10i64, 20, 30]),
+    ]);
+    let result = executor.execute(&block)?;
+
+    let expected = vec![
+        "+---------+---------+",
+        "| (a + b) | (b + a) |",
+        "+---------+---------+",
+        "| 11      | 11      |",
+        "| 22      | 22      |",
+        "| 33      | 33      |",
+        "+---------+---------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[result]);
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_execute_batch() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::Int64, false)]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema,
+        vec![add(col("a"), lit(1i64)).alias("c")],
+        true,
+    )?;
+
+    let blocks = vec![
+        DataBlock::create_by_array(input_schema.clone(), vec![Series::new(vec![1i64, 2])]),
+        DataBlock::create_by_array(input_schema, vec![Series::new(vec![3i64])]),
+    ];
+    let results = executor.execute_batch(&blocks)?;
+    assert_eq!(results.len(), 2);
+
+    let expected = vec![
+        "+---+", //
+        "| c |", //
+        "+---+", //
+        "| 2 |", //
+        "| 3 |", //
+        "| 4 |", //
+        "+---+", //
+    ];
+    common_datablocks::assert_blocks_eq(expected, &results);
+
+    // Blocks of different schemas.
+    let other_schema = DataSchemaRefExt::create(vec![DataField::new("b", DataType::Int64, false)]);
+    let other = DataBlock::create_by_array(other_schema, vec![Series::new(vec![1i64])]);
+    let error = executor
+        .execute_batch(&[blocks[0].clone(), other.clone()])
+        .unwrap_err();
+    assert_eq!(error.code(), ErrorCode::logical_error_code());
+
+    // Blocks of the same schema, but it is not the input schema.
+    let error = executor.execute_batch(&[other.clone(), other]).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::logical_error_code());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_order_by_input() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+    let output_schema = DataSchemaRefExt::create(vec![
+        DataField::new("(a + 1)", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+        DataField::new("a", DataType::Int64, false),
+    ]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema,
+        vec![add(col("a"), lit(1i64)), col("b"), col("a")],
+        false,
+    )?
+    .order_by_input(true);
+
+    let block = DataBlock::create_by_array(input_schema, vec![
+        Series::new(vec![1i64, 2]),
+        Series::new(vec![10i64, 20]),
+    ]);
+    let result = executor.execute(&block)?;
+
+    let names = result
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["a", "b", "(a + 1)"]);
+
+    let expected = vec![
+        "+---+----+---------+",
+        "| a | b  | (a + 1) |",
+        "+---+----+---------+",
+        "| 1 | 10 | 2       |",
+        "| 2 | 20 | 3       |",
+        "+---+----+---------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[result]);
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_unknown_projection_column() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("c", DataType::Int64, false),
+    ]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema,
+        vec![col("a")],
+        false,
+    )?;
+
+    let block = DataBlock::create_by_array(input_schema, vec![Series::new(vec![1i64, 2])]);
+    let error = executor.execute(&block).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::unknown_column_code());
+    assert_eq!(
+        error.message(),
+        "Projection column c is not produced by the expressions, output schema: [a, c]"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_constant_only() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let exprs = vec![lit(1i64), lit("x".as_bytes())];
+    let output_schema = DataSchemaRefExt::create(
+        exprs
+            .iter()
+            .map(|expr| expr.to_data_field(&input_schema))
+            .collect::<Result<Vec<_>>>()?,
+    );
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema.clone(),
+        exprs,
+        false,
+    )?;
+
+    for rows in [0, 3] {
+        let block =
+            DataBlock::create_by_array(input_schema.clone(), vec![Series::new(vec![1i64; rows])]);
+        let result = executor.execute(&block)?;
+        assert_eq!(result.schema(), &output_schema);
+        assert_eq!(result.num_rows(), rows);
+        for column in result.columns() {
+            assert!(matches!(column, DataColumn::Constant(_, n) if *n == rows));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_dry_run() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::Int64, false)]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        input_schema.clone(),
+        output_schema.clone(),
+        vec![add(col("a"), lit(1i64)).alias("c")],
+        true,
+    )?;
+
+    let sample = DataBlock::create_by_array(input_schema.clone(), vec![Series::new(vec![1i64])]);
+    assert_eq!(executor.dry_run(&sample)?, output_schema);
+
+    // The column a is declared as Int64, but holds strings.
+    let sample = DataBlock::create_by_array(input_schema, vec![Series::new(vec!["x"])]);
+    let error = executor.dry_run(&sample).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_data_value_type_code());
+    assert_eq!(
+        error.message(),
+        "Column a of the sample is String, but the input schema of test declares Int64"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_max_actions() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new("c", DataType::Int64, false)]);
+
+    // a, 1, (a + 1), 1, ((a + 1) + 1), c
+    let expr = add(add(col("a"), lit(1i64)), lit(1i64)).alias("c");
+
+    let executor = ExpressionExecutor::try_create_with_limits(
+        "test",
+        input_schema.clone(),
+        output_schema.clone(),
+        vec![expr.clone()],
+        true,
+        ExpressionLimits {
+            max_actions: 6,
+            ..Default::default()
+        },
+    );
+    assert!(executor.is_ok());
+
+    let executor = ExpressionExecutor::try_create_with_limits(
+        "test",
+        input_schema,
+        output_schema,
+        vec![expr],
+        true,
+        ExpressionLimits {
+            max_actions: 5,
+            ..Default::default()
+        },
+    );
+    let error = executor.unwrap_err();
+    assert_eq!(error.code(), ErrorCode::bad_arguments_code());
+    assert_eq!(
+        error.message(),
+        "Expression chain has 6 actions, exceeds the maximum 5"
+    );
+
+    Ok(())
+}
+
+#[derive(Clone)]
+struct MismatchedTypeFunction;
+
+impl Function for MismatchedTypeFunction {
+    fn name(&self) -> &str {
+        "mismatched"
+    }
+
+    fn return_type(&self, _args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        Ok(DataTypeAndNullable::create(&DataType::Int64, false))
+    }
+
+    // Declared as Int64, but returns a String column.
+    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        Ok(DataColumn::Constant(
+            DataValue::String(Some(b"x".to_vec())),
+            input_rows,
+        ))
+    }
+}
+
+impl fmt::Display for MismatchedTypeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "mismatched")
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_expression_executor_mismatched_column_type() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema = DataSchemaRefExt::create(vec![DataField::new(
+        "mismatched(a)",
+        DataType::Int64,
+        false,
+    )]);
+
+    let chain = ExpressionChain {
+        schema: input_schema.clone(),
+        actions: vec![
+            ExpressionAction::Input(ActionInput {
+                name: "a".to_string(),
+                return_type: DataType::Int64,
+            }),
+            ExpressionAction::Function(ActionFunction {
+                name: "mismatched(a)".to_string(),
+                func_name: "mismatched".to_string(),
+                return_type: DataType::Int64,
+                is_nullable: false,
+                func: Box::new(MismatchedTypeFunction),
+                arg_names: vec!["a".to_string()],
+                arg_types: vec![DataTypeAndNullable::create(&DataType::Int64, false)],
+            }),
+        ],
+    };
+
+    let executor = ExpressionExecutor::try_create_with_chain(
+        "test",
+        input_schema.clone(),
+        output_schema,
+        chain,
+        false,
+        DuplicateAlias::Error,
+    )?;
+
+    let block = DataBlock::create_by_array(input_schema, vec![Series::new(vec![1i64, 2])]);
+    let error = executor.execute(&block).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::logical_error_code());
+    assert_eq!(
+        error.message(),
+        "Column mismatched(a) is declared as Int64, but the executed column is String"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_executor_execute_arc_identity() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::Int64, false),
+    ]);
+
+    let executor = ExpressionExecutor::try_create(
+        "test",
+        schema.clone(),
+        schema.clone(),
+        vec![col("a"), col("b")],
+        false,
+    )?;
+
+    let block = Arc::new(DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1i64, 2]),
+        Series::new(vec![3i64, 4]),
+    ]));
+    let result = executor.execute_arc(block.clone())?;
+
+    for (input, output) in block.columns().iter().zip(result.columns()) {
+        match (input, output) {
+            (DataColumn::Array(input), DataColumn::Array(output)) => {
+                assert!(Arc::ptr_eq(&input.0, &output.0))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone)]
+struct CountingFunction {
+    calls: Arc<AtomicUsize>,
+}
+
+impl Function for CountingFunction {
+    fn name(&self) -> &str {
+        "counting"
+    }
+
+    fn return_type(&self, _args: &[DataTypeAndNullable]) -> Result<DataTypeAndNullable> {
+        Ok(DataTypeAndNullable::create(&DataType::Int64, false))
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        Ok(columns[0].column().clone())
+    }
+}
+
+impl fmt::Display for CountingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "counting")
+    }
+}
+
+#[test]
+fn test_expression_executor_reuse_identical_functions() -> Result<()> {
+    let input_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let output_schema =
+        DataSchemaRefExt::create(vec![DataField::new("counting(a)", DataType::Int64, false)]);
+
+    // The determinism is read from the registered features of func_name: abs is deterministic, rand is not.
+    for (func_name, expect_calls) in [("abs", 1), ("rand", 2)] {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let function = ExpressionAction::Function(ActionFunction {
+            name: "counting(a)".to_string(),
+            func_name: func_name.to_string(),
+            return_type: DataType::Int64,
+            is_nullable: false,
+            func: Box::new(CountingFunction {
+                calls: calls.clone(),
+            }),
+            arg_names: vec!["a".to_string()],
+            arg_types: vec![DataTypeAndNullable::create(&DataType::Int64, false)],
+        });
+
+        let chain = ExpressionChain {
+            schema: input_schema.clone(),
+            actions: vec![
+                ExpressionAction::Input(ActionInput {
+                    name: "a".to_string(),
+                    return_type: DataType::Int64,
+                }),
+                function.clone(),
+                function,
+            ],
+        };
+
+        let executor = ExpressionExecutor::try_create_with_chain(
+            "test",
+            input_schema.clone(),
+            output_schema.clone(),
+            chain,
+            false,
+            DuplicateAlias::Error,
+        )?;
+
+        let block =
+            DataBlock::create_by_array(input_schema.clone(), vec![Series::new(vec![1i64, 2])]);
+        let result = executor.execute(&block)?;
+        assert_eq!(result.num_rows(), 2);
+        assert_eq!(calls.load(Ordering::Relaxed), expect_calls);
+    }
+
+    Ok(())
+}
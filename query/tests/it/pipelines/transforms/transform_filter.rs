@@ -40,6 +40,7 @@ async fn test_transform_filter() -> Result<()> {
             Ok(Box::new(WhereTransform::try_create(
                 plan.input.schema(),
                 plan.predicate.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
     }
@@ -77,7 +78,8 @@ async fn test_transform_filter_error() -> Result<()> {
         .and_then(|x| x.build())?;
 
     if let PlanNode::Filter(plan) = plan {
-        let result = WhereTransform::try_create(plan.schema(), plan.predicate);
+        let result =
+            WhereTransform::try_create(plan.schema(), plan.predicate, ExpressionLimits::default());
         let actual = format!("{}", result.err().unwrap());
         let expect = "Code: 1006, displayText = Unable to get field named \"not_found_filed\". Valid fields: [\"number\"].";
         assert_eq!(expect, actual);
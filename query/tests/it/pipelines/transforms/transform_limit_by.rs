@@ -44,6 +44,7 @@ async fn test_transform_limit_by() -> Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.exprs.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
 
@@ -60,6 +61,7 @@ async fn test_transform_limit_by() -> Result<()> {
                 plan.schema(),
                 DataSchemaRefExt::create(vec![col("(number % 3)").to_data_field(&plan.schema())?]),
                 vec![col("(number % 3)"), col("number")],
+                ExpressionLimits::default(),
             )?))
         })?;
     }
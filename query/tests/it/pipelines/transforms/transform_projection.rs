@@ -40,6 +40,7 @@ async fn test_transform_projection() -> Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.expr.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
         pipeline.add_simple_transform(|| {
@@ -47,6 +48,7 @@ async fn test_transform_projection() -> Result<()> {
                 plan.input.schema(),
                 plan.schema.clone(),
                 plan.expr.clone(),
+                ExpressionLimits::default(),
             )?))
         })?;
     }
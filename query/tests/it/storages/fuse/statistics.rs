@@ -14,6 +14,7 @@
 //
 
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
 use common_datavalues::prelude::Series;
 use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::DataField;
@@ -38,6 +39,39 @@ fn test_ft_stats_block_stats() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_block_stats_with_null() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Int32, true),
+        DataField::new("c", DataType::Null, true),
+    ]);
+    let block = DataBlock::create(schema, vec![
+        Series::new(vec![Some(2), None, Some(1), Some(3), None]).into(),
+        Series::new(vec![None::<i32>, None, None, None, None]).into(),
+        DataColumn::Constant(DataValue::Null, 5),
+    ]);
+    let r = StatisticsAccumulator::acc_columns(&block)?;
+    assert_eq!(3, r.len());
+
+    // partially null, min/max are computed over the non-null values
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.min, DataValue::Int32(Some(1)));
+    assert_eq!(col_stats.max, DataValue::Int32(Some(3)));
+    assert_eq!(col_stats.null_count, 2);
+    assert!(col_stats.has_null());
+
+    // all null, no min/max
+    for id in [1, 2] {
+        let col_stats = r.get(&id).unwrap();
+        assert!(col_stats.min.is_null());
+        assert!(col_stats.max.is_null());
+        assert_eq!(col_stats.null_count, 5);
+        assert!(col_stats.has_null());
+    }
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     let num_of_blocks = 10;
@@ -96,6 +96,29 @@ fn test_bloom_interval() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_bloom_add_find_nullable() -> Result<()> {
+    let mut bloom = BloomFilter::with_rate(4, 0.000001, create_seeds());
+
+    // Only the valid values are added.
+    let col = DataColumn::Array(Series::new([Some(1_i32), None, Some(3_i32), None]));
+    bloom.add(&col)?;
+    assert!(bloom.find(DataValue::Int32(Some(1)))?);
+    assert!(bloom.find(DataValue::Int32(Some(3)))?);
+
+    // A null is never found.
+    assert!(!bloom.find(DataValue::Null)?);
+    assert!(!bloom.find(DataValue::Int32(None))?);
+
+    // An all-null column adds nothing.
+    let mut empty = bloom.clone_empty();
+    empty.add(&DataColumn::Constant(DataValue::Null, 4))?;
+    empty.add(&DataColumn::Array(Series::new([None::<i32>, None])))?;
+    assert!(empty.bitmap().none());
+
+    Ok(())
+}
+
 #[test]
 fn test_bloom_f64_serialization() -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("Float64", DataType::Float64, true)]);
@@ -38,7 +38,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 58);
+    assert_eq!(block.num_rows(), 73);
 
     let expected = vec![
         "+--------------------------------------+------------------+---------+-------------+",
@@ -57,7 +57,10 @@ async fn test_configs_table() -> Result<()> {
         "| disk.data_path                       | _data            | storage |             |",
         "| disk.temp_data_path                  |                  | storage |             |",
         "| flight_api_address                   | 127.0.0.1:9090   | query   |             |",
+        "| flight_max_rows_per_batch            | 65536            | query   |             |",
         "| http_api_address                     | 127.0.0.1:8080   | query   |             |",
+        "| http_compression_min_size            | 1024             | query   |             |",
+        "| http_config_timeout_ms               | 5000             | query   |             |",
         "| http_handler_host                    | 127.0.0.1        | query   |             |",
         "| http_handler_port                    | 8000             | query   |             |",
         "| http_handler_tls_server_cert         |                  | query   |             |",
@@ -66,17 +69,29 @@ async fn test_configs_table() -> Result<()> {
         "| log_dir                              | ./_logs          | log     |             |",
         "| log_level                            | INFO             | log     |             |",
         "| max_active_sessions                  | 256              | query   |             |",
+        "| max_expression_actions               | 8192             | query   |             |",
+        "| max_expression_depth                 | 1024             | query   |             |",
         "| max_query_log_size                   | 10000            | query   |             |",
         "| meta_address                         |                  | meta    |             |",
+        "| meta_client_idle_timeout_in_second   | 0                | meta    |             |",
+        "| meta_client_max_retries              | 0                | meta    |             |",
+        "| meta_client_max_token_renewals       | 2                | meta    |             |",
+        "| meta_client_max_concurrent_requests  | 0                | meta    |             |",
         "| meta_client_timeout_in_second        | 10               | meta    |             |",
+        "| meta_kv_checksum                     | false            | meta    |             |",
         "| meta_embedded_dir                    | ./_meta_embedded | meta    |             |",
         "| meta_password                        |                  | meta    |             |",
+        "| meta_request_timeout_in_second       | 0                | meta    |             |",
         "| meta_username                        | root             | meta    |             |",
         "| metric_api_address                   | 127.0.0.1:7070   | query   |             |",
         "| mysql_handler_host                   | 127.0.0.1        | query   |             |",
         "| mysql_handler_port                   | 3307             | query   |             |",
+        "| null_display                         | NULL             | query   |             |",
         "| num_cpus                             | 8                | query   |             |",
+        "| read_only                            | false            | query   |             |",
         "| management_mode                      | false            | query   |             |",
+        "| management_mode_superuser            |                  | query   |             |",
+        "| management_mode_allowed_statements   |                  | query   |             |",
         "| rpc_tls_meta_server_root_ca_cert     |                  | meta    |             |",
         "| rpc_tls_meta_service_domain_name     | localhost        | meta    |             |",
         "| rpc_tls_query_server_root_ca_cert    |                  | query   |             |",
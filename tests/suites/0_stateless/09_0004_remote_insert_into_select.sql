@@ -36,13 +36,19 @@ select sum(cast(a as uint64)), sum(cast(b as uint64)) from s1;
 
 
 -- default
-create table d1(n String, a UInt8 not null, b Int16 default a + 3, c String default 'c');
+create table d1(n String, a UInt8 not null default 0, b Int16 default a + 3, c String default 'c');
 insert into d1(a) values (1);
 insert into d1(b) values (2);
 -- https://github.com/datafuselabs/databend/issues/3636
 insert into d1(b) select b from d1;
 select * from d1 order by a, b;
 
+-- not nullable column without default value must be specified
+create table d2(a UInt8 not null, b Int16);
+insert into d2(b) values (1); -- {ErrorCode 1006}
+insert into d2(a) values (1);
+select * from d2;
+
 
 
 DROP TABLE t1;
@@ -52,5 +58,6 @@ DROP TABLE n2;
 DROP TABLE n3;
 DROP TABLE s1;
 DROP TABLE d1;
+DROP TABLE d2;
 
 DROP DATABASE db_09_004;